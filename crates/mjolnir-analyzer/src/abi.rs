@@ -0,0 +1,249 @@
+//! A parsed contract ABI and the findings that come from comparing it
+//! against the parsed source, rather than the source alone.
+//!
+//! The source-level rules (see [`crate::rules`]) and the taint pass (see
+//! [`crate::taint`]) can only ever flag what's visible in a single file.
+//! Some mismatches - an interface promising a function the source never
+//! implements, an event declared but never emitted, a `payable` function
+//! with no guard - only show up once the ABI is known to be the source of
+//! truth, the same role it plays for `ethabi`/`ethabi-derive`.
+
+use crate::ast::{SourceUnit, Statement};
+use crate::models::{Category, Issue, Severity};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// A single input/output parameter in an ABI entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbiParam {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+/// A function entry from a contract ABI.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbiFunction {
+    pub name: String,
+    #[serde(default)]
+    pub inputs: Vec<AbiParam>,
+    #[serde(default)]
+    pub payable: bool,
+    #[serde(default)]
+    pub state_mutability: Option<String>,
+}
+
+impl AbiFunction {
+    /// Whether the ABI marks this function as accepting value, either via
+    /// the legacy `payable: true` flag or `stateMutability: "payable"`.
+    pub fn is_payable(&self) -> bool {
+        self.payable || self.state_mutability.as_deref() == Some("payable")
+    }
+}
+
+/// An event entry from a contract ABI.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbiEvent {
+    pub name: String,
+    #[serde(default)]
+    pub inputs: Vec<AbiParam>,
+}
+
+/// A contract ABI: the functions and events declared on the interface,
+/// independent of what the source actually implements.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ContractAbi {
+    #[serde(default)]
+    pub functions: Vec<AbiFunction>,
+    #[serde(default)]
+    pub events: Vec<AbiEvent>,
+}
+
+impl ContractAbi {
+    /// Parse a contract ABI from its JSON representation.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+/// Flags ABI-declared payable functions with no visible access-control
+/// guard - the same guard [`crate::taint::access_control_findings`] looks
+/// for, scoped down to just the functions the ABI marks `payable`.
+pub fn unguarded_payable_findings(unit: &SourceUnit, abi: &ContractAbi) -> Vec<Issue> {
+    let payable_names: HashSet<&str> = abi
+        .functions
+        .iter()
+        .filter(|f| f.is_payable())
+        .map(|f| f.name.as_str())
+        .collect();
+    if payable_names.is_empty() {
+        return Vec::new();
+    }
+
+    let mut issues = Vec::new();
+
+    for contract in &unit.contracts {
+        for function in &contract.functions {
+            if function.is_private || !payable_names.contains(function.name.as_str()) {
+                continue;
+            }
+
+            let has_guard = function
+                .body
+                .iter()
+                .any(|stmt| matches!(stmt, Statement::Guard { .. }))
+                || function
+                    .modifiers
+                    .iter()
+                    .any(|m| crate::taint::GUARD_MODIFIERS.contains(&m.as_str()));
+
+            if has_guard {
+                continue;
+            }
+
+            issues.push(Issue {
+                start: Some(function.span.start),
+                end: Some(function.span.end),
+                rule_id: String::new(),
+                category: Category::Security,
+                severity: Severity::Medium,
+                message: format!(
+                    "ABI marks '{}' as payable, but it has no visible access-control guard",
+                    function.name
+                ),
+                line: Some(function.span.start.line),
+                recommendation: Some(
+                    "Guard payable functions with an access-control modifier or a require(msg.sender == ...) check".to_string(),
+                ),
+            });
+        }
+    }
+
+    issues
+}
+
+/// Flags ABI-declared events that are never emitted anywhere in the source.
+pub fn unemitted_event_findings(unit: &SourceUnit, abi: &ContractAbi) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    for event in &abi.events {
+        let needle = format!("emit {}", event.name);
+        let emitted = unit.contracts.iter().any(|contract| {
+            contract
+                .functions
+                .iter()
+                .any(|function| function.body.iter().any(|stmt| stmt.text().contains(&needle)))
+        });
+
+        if emitted {
+            continue;
+        }
+
+        issues.push(Issue {
+            start: None,
+            end: None,
+            rule_id: String::new(),
+            category: Category::CodeQuality,
+            severity: Severity::Low,
+            message: format!(
+                "Event '{}' is declared in the ABI but never emitted in source",
+                event.name
+            ),
+            line: None,
+            recommendation: Some(format!(
+                "Emit '{}' where the corresponding state change occurs, or remove it from the ABI",
+                event.name
+            )),
+        });
+    }
+
+    issues
+}
+
+/// Flags ABI-declared functions that are missing from the parsed source.
+pub fn missing_function_findings(unit: &SourceUnit, abi: &ContractAbi) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    for function in &abi.functions {
+        let present = unit
+            .contracts
+            .iter()
+            .any(|contract| contract.functions.iter().any(|f| f.name == function.name));
+
+        if present {
+            continue;
+        }
+
+        issues.push(Issue {
+            start: None,
+            end: None,
+            rule_id: String::new(),
+            category: Category::CodeQuality,
+            severity: Severity::Medium,
+            message: format!(
+                "Function '{}' is declared in the ABI but missing from source",
+                function.name
+            ),
+            line: None,
+            recommendation: Some("Implement the function, or remove it from the ABI".to_string()),
+        });
+    }
+
+    issues
+}
+
+/// Flags public setters (by ABI name convention and declared inputs) whose
+/// body has no visible `require(...)`/`assert(...)` validation call.
+pub fn unvalidated_setter_findings(unit: &SourceUnit, abi: &ContractAbi) -> Vec<Issue> {
+    let setters_with_inputs: HashSet<&str> = abi
+        .functions
+        .iter()
+        .filter(|f| f.name.starts_with("set") && !f.inputs.is_empty())
+        .map(|f| f.name.as_str())
+        .collect();
+    if setters_with_inputs.is_empty() {
+        return Vec::new();
+    }
+
+    let mut issues = Vec::new();
+
+    for contract in &unit.contracts {
+        for function in &contract.functions {
+            if function.is_private || !setters_with_inputs.contains(function.name.as_str()) {
+                continue;
+            }
+
+            let has_validation = function
+                .body
+                .iter()
+                .any(|stmt| is_validation_call(stmt.text()));
+
+            if has_validation {
+                continue;
+            }
+
+            issues.push(Issue {
+                start: Some(function.span.start),
+                end: Some(function.span.end),
+                rule_id: String::new(),
+                category: Category::CodeQuality,
+                severity: Severity::Low,
+                message: format!(
+                    "Setter '{}' takes ABI inputs but has no visible validation",
+                    function.name
+                ),
+                line: Some(function.span.start.line),
+                recommendation: Some(
+                    "Validate setter inputs with require(...) before writing to storage"
+                        .to_string(),
+                ),
+            });
+        }
+    }
+
+    issues
+}
+
+fn is_validation_call(text: &str) -> bool {
+    text.contains("require(") || text.contains("assert(")
+}
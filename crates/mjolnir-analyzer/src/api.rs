@@ -1,6 +1,6 @@
 //! API integration for the analyzer
 
-use crate::{AnalysisResults, AnalyzerConfig, analyze_contract};
+use crate::{AnalysisResults, AnalyzerConfig, ContractAbi};
 use serde::{Deserialize, Serialize};
 
 /// Request format for the analyzer API
@@ -11,6 +11,10 @@ pub struct AnalyzeRequest {
     /// Optional configuration for the analyzer
     #[serde(default)]
     pub config: Option<AnalyzerConfig>,
+    /// Optional contract ABI to reconcile the source against (see
+    /// [`crate::Analyzer::analyze_with_abi`])
+    #[serde(default)]
+    pub abi: Option<ContractAbi>,
 }
 
 /// Response format for the analyzer API
@@ -28,11 +32,13 @@ pub fn process_request(request: &str) -> Result<String, String> {
         serde_json::from_str(request).map_err(|e| format!("Failed to parse request: {}", e))?;
 
     // Analyze the contract
-    let results = if let Some(config) = analyze_request.config {
-        let analyzer = crate::Analyzer::with_config(config);
-        analyzer.analyze(&analyze_request.code)
-    } else {
-        analyze_contract(&analyze_request.code)
+    let analyzer = analyze_request
+        .config
+        .map(crate::Analyzer::with_config)
+        .unwrap_or_else(crate::Analyzer::new);
+    let results = match &analyze_request.abi {
+        Some(abi) => analyzer.analyze_with_abi(&analyze_request.code, abi),
+        None => analyzer.analyze(&analyze_request.code),
     };
 
     // Create the response
@@ -80,4 +86,41 @@ mod tests {
 
         assert!(parsed.get("score").is_some());
     }
+
+    #[test]
+    fn test_process_request_with_abi() {
+        let request = r#"{
+            "code": "contract Test { function transfer() public { /* code */ } }",
+            "abi": {
+                "functions": [
+                    { "name": "transfer", "inputs": [] },
+                    { "name": "withdraw", "inputs": [], "payable": true }
+                ],
+                "events": [
+                    { "name": "Transfer", "inputs": [] }
+                ]
+            }
+        }"#;
+
+        let response = process_request(request).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&response).unwrap();
+
+        let messages: Vec<&str> = parsed["issues"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|issue| issue["message"].as_str().unwrap())
+            .collect();
+
+        assert!(
+            messages
+                .iter()
+                .any(|m| m.contains("withdraw") && m.contains("missing from source"))
+        );
+        assert!(
+            messages
+                .iter()
+                .any(|m| m.contains("Transfer") && m.contains("never emitted"))
+        );
+    }
 }
@@ -0,0 +1,111 @@
+//! A lightweight Solidity AST produced by [`crate::parser`].
+//!
+//! The tree only captures the constructs the analysis rules actually reason
+//! about (contracts, functions, and a handful of statement shapes needed to
+//! track external calls and storage writes); it is not a faithful
+//! reproduction of the full Solidity grammar.
+
+use serde::{Deserialize, Serialize};
+
+/// A 1-based source position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// A half-open range of source positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Span {
+    pub fn single_line(line: usize, start_col: usize, end_col: usize) -> Self {
+        Self {
+            start: Position {
+                line,
+                column: start_col,
+            },
+            end: Position {
+                line,
+                column: end_col,
+            },
+        }
+    }
+}
+
+/// The root of a parsed source file: zero or more contracts.
+#[derive(Debug, Clone, Default)]
+pub struct SourceUnit {
+    pub contracts: Vec<Contract>,
+}
+
+/// A `contract Name { ... }` declaration.
+#[derive(Debug, Clone)]
+pub struct Contract {
+    pub name: String,
+    pub span: Span,
+    pub functions: Vec<Function>,
+}
+
+/// A function declared inside a contract, along with its parsed body.
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub name: String,
+    pub span: Span,
+    /// Modifier names applied to the function signature (e.g. `onlyOwner`),
+    /// used by the access-control taint pass alongside inline
+    /// [`Statement::Guard`] statements.
+    pub modifiers: Vec<String>,
+    /// Whether the signature is declared `private`/`internal`; such
+    /// functions aren't externally reachable so the access-control rule
+    /// skips them.
+    pub is_private: bool,
+    pub body: Vec<Statement>,
+}
+
+/// A coarse-grained statement inside a function body.
+///
+/// Rules that need precise syntax (e.g. distinguishing `a.call()` from a
+/// comment containing the word "call") should match on these variants
+/// instead of re-scanning the raw source text. Each variant carries its
+/// trimmed source line so the taint pass in [`crate::taint`] can check
+/// whether a later statement reads a value established by an earlier one
+/// without re-reading the file.
+#[derive(Debug, Clone)]
+pub enum Statement {
+    /// An external call such as `x.call(...)`, `.transfer(...)`, `.send(...)`.
+    ExternalCall { span: Span, text: String },
+    /// A write into a storage-like identifier, e.g. `balances[msg.sender] -= amount;`.
+    StorageWrite {
+        target: String,
+        span: Span,
+        text: String,
+    },
+    /// An inline authority check, e.g. `require(msg.sender == owner);`.
+    Guard { span: Span, text: String },
+    /// Any statement that doesn't affect reentrancy/storage/guard analysis.
+    Other { span: Span, text: String },
+}
+
+impl Statement {
+    pub fn span(&self) -> Span {
+        match self {
+            Statement::ExternalCall { span, .. }
+            | Statement::StorageWrite { span, .. }
+            | Statement::Guard { span, .. }
+            | Statement::Other { span, .. } => *span,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        match self {
+            Statement::ExternalCall { text, .. }
+            | Statement::StorageWrite { text, .. }
+            | Statement::Guard { text, .. }
+            | Statement::Other { text, .. } => text,
+        }
+    }
+}
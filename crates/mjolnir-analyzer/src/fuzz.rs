@@ -0,0 +1,211 @@
+//! Turns high-severity reentrancy/overflow findings into runnable fuzz test
+//! scaffolds, in the same spirit as the `honggfuzz`-backed arithmetic
+//! fuzzers used across Substrate: rather than leaving a finding as just a
+//! line number, emit a harness stub that varies the implicated function's
+//! inputs and asserts the invariant the finding says is at risk.
+//!
+//! This module only builds the scaffold in memory (see [`FuzzHarness`]); it
+//! doesn't run anything. Pairing it with an actual fuzzing loop - like the
+//! `honggfuzz` target at the repo root's `fuzz/` crate - is left to the
+//! caller, via [`export_harnesses`].
+
+use crate::models::{Issue, Severity};
+use std::path::Path;
+
+/// A single fuzzed input slot for a generated harness, taken from the
+/// implicated function's signature.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzInput {
+    pub name: String,
+    pub ty: String,
+}
+
+/// A property a generated harness should check, matched to the rule that
+/// triggered it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Invariant {
+    /// Checks-effects-interactions: no external call should happen before
+    /// the state it reads is fully updated (the same pattern the
+    /// reentrancy taint pass checks for).
+    NoExternalCallBeforeStateUpdate,
+    /// Arithmetic should never underflow below zero (see the
+    /// `integer_overflow` rule).
+    NoNegativeSubtraction,
+    /// A transfer/withdraw path should never change the sum of balances.
+    BalanceConservation,
+}
+
+impl Invariant {
+    /// A human-readable description of the property, used as the generated
+    /// harness's assertion placeholder.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Invariant::NoExternalCallBeforeStateUpdate => {
+                "no external call happens before the state it reads is fully updated"
+            }
+            Invariant::NoNegativeSubtraction => "no subtraction underflows below zero",
+            Invariant::BalanceConservation => "the sum of balances is unchanged by the call",
+        }
+    }
+}
+
+/// A runnable fuzz test scaffold targeting one function implicated by a
+/// high-severity issue.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzHarness {
+    pub function: String,
+    /// The id of the rule whose finding produced this harness (see
+    /// [`crate::AnalysisRule::id`]).
+    pub rule_id: String,
+    pub inputs: Vec<FuzzInput>,
+    pub invariants: Vec<Invariant>,
+}
+
+impl FuzzHarness {
+    /// Render this harness as a standalone `honggfuzz` target source file,
+    /// ready to drop into a `fuzz_targets/` directory (see `/fuzz` at the
+    /// repo root).
+    pub fn to_rust_source(&self) -> String {
+        let args = self
+            .inputs
+            .iter()
+            .map(|input| format!("{}: {}", input.name, input.ty))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let invariants = self
+            .invariants
+            .iter()
+            .map(|invariant| format!("//   - {}", invariant.description()))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            "//! Generated fuzz harness for `{function}` ({rule_id}).\n\
+             //! Invariants to assert:\n\
+             {invariants}\n\
+             use honggfuzz::fuzz;\n\
+             \n\
+             fn main() {{\n\
+             \u{20}   loop {{\n\
+             \u{20}       fuzz!(|({args})| {{\n\
+             \u{20}           // TODO: call `{function}` with the fuzzed inputs above and\n\
+             \u{20}           // assert the invariants listed up top.\n\
+             \u{20}       }});\n\
+             \u{20}   }}\n\
+             }}\n",
+            function = self.function,
+            rule_id = self.rule_id,
+            invariants = invariants,
+            args = args,
+        )
+    }
+}
+
+/// Write each harness to `dir/<function>_fuzz.rs`, creating `dir` if needed.
+pub fn export_harnesses(harnesses: &[FuzzHarness], dir: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    for harness in harnesses {
+        let path = dir.join(format!("{}_fuzz.rs", harness.function));
+        std::fs::write(path, harness.to_rust_source())?;
+    }
+    Ok(())
+}
+
+/// Build a [`FuzzHarness`] for a high-severity reentrancy or
+/// integer-overflow issue, if the implicated function can be located.
+/// Returns `None` for any other rule or severity, or if no enclosing
+/// function header can be found above the issue's line.
+pub(crate) fn harness_for_issue(issue: &Issue, code: &str) -> Option<FuzzHarness> {
+    if issue.severity != Severity::High {
+        return None;
+    }
+
+    let mut invariants = match issue.rule_id.as_str() {
+        "reentrancy" => vec![Invariant::NoExternalCallBeforeStateUpdate],
+        "integer_overflow" => vec![Invariant::NoNegativeSubtraction],
+        _ => return None,
+    };
+
+    let header = function_header_at_or_before(code, issue.line?)?;
+    let function = function_name(&header)?;
+
+    if header.contains("balance") || function.contains("withdraw") || function.contains("transfer")
+    {
+        invariants.push(Invariant::BalanceConservation);
+    }
+
+    Some(FuzzHarness {
+        function,
+        rule_id: issue.rule_id.clone(),
+        inputs: function_params(&header),
+        invariants,
+    })
+}
+
+/// The nearest `fn `/`function ` header at or above `issue_line` (1-based).
+fn function_header_at_or_before(code: &str, issue_line: usize) -> Option<String> {
+    code.lines()
+        .take(issue_line)
+        .rev()
+        .map(str::trim)
+        .find(|line| (line.contains("fn ") || line.contains("function ")) && line.contains('('))
+        .map(str::to_string)
+}
+
+/// Extract the function name from a header line, e.g.
+/// `function withdraw(uint amount) public {` -> `Some("withdraw")`.
+fn function_name(header: &str) -> Option<String> {
+    let name = header
+        .split_once("function ")
+        .or_else(|| header.split_once("fn "))
+        .and_then(|(_, rest)| rest.split('(').next())?
+        .trim()
+        .to_string();
+    (!name.is_empty()).then_some(name)
+}
+
+/// Extract `(type name, ...)` parameters from a header line into fuzzed
+/// input slots.
+fn function_params(header: &str) -> Vec<FuzzInput> {
+    let Some(open) = header.find('(') else {
+        return Vec::new();
+    };
+
+    let mut depth = 0i32;
+    let mut close = None;
+    for (i, c) in header[open..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    close = Some(open + i);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    let Some(close) = close else {
+        return Vec::new();
+    };
+
+    header[open + 1..close]
+        .split(',')
+        .filter_map(|param| {
+            let param = param.trim();
+            if param.is_empty() {
+                return None;
+            }
+            let mut words: Vec<&str> = param.split_whitespace().collect();
+            let name = words.pop()?.trim_start_matches(['*', '&']).to_string();
+            let ty = if words.is_empty() {
+                "bytes".to_string()
+            } else {
+                words.join(" ")
+            };
+            Some(FuzzInput { name, ty })
+        })
+        .collect()
+}
@@ -0,0 +1,373 @@
+//! A per-operation gas cost model, in the same spirit as Substrate's
+//! `base_extrinsic` weight accounting where every dispatchable carries a
+//! fixed weight that gets summed per block: every recognized EVM-ish
+//! construct here carries a fixed estimated cost, summed per function.
+//!
+//! This doesn't run the EVM - it's a heuristic scan over the source, same
+//! as the rest of this crate's rules - but it gives a per-function estimate
+//! instead of a flat "found N suspicious patterns" count.
+
+use crate::models::AnalyzerConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Default gas budget a function is measured against when deriving
+/// [`crate::models::Metrics::gas_efficiency`].
+pub const DEFAULT_GAS_BUDGET: u64 = 100_000;
+
+/// Per-construct gas weights. Fields map onto `AnalyzerConfig.custom_weights`
+/// keys (`"gas_sstore"`, `"gas_sload"`, ...) so callers can override
+/// individual costs without recompiling.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GasModel {
+    /// Writing to contract storage (`SSTORE`).
+    pub sstore: u64,
+    /// Reading from contract storage (`SLOAD`).
+    pub sload: u64,
+    /// An external call or native token transfer (`.call`/`.transfer`/`.send`).
+    pub external_call: u64,
+    /// Base cost of a `for`/`while` loop body, before the per-iteration penalty.
+    pub loop_base: u64,
+    /// Multiplier applied to a loop's body cost since the iteration count is
+    /// unknown at analysis time - this is a penalty factor, not a flat add.
+    pub loop_unknown_iterations_penalty: u64,
+    /// Growing the memory region (`memory`/`bytes`/`string` allocations).
+    pub memory_expansion: u64,
+    /// Writing to a slot already touched earlier in the same function
+    /// (nonzero -> nonzero, EVM's "dirty" SSTORE case) - cheaper than a
+    /// cold init since the slot is already warm.
+    pub sstore_dirty: u64,
+    /// Gas refunded for clearing a slot to zero (nonzero -> zero), mirroring
+    /// the EVM `Substate` refund counter accrued on `SSTORE`-to-zero.
+    pub sstore_clear_refund: u64,
+}
+
+impl Default for GasModel {
+    fn default() -> Self {
+        Self {
+            sstore: 20_000,
+            sload: 2_100,
+            external_call: 2_600,
+            loop_base: 200,
+            loop_unknown_iterations_penalty: 50,
+            memory_expansion: 3,
+            sstore_dirty: 2_900,
+            sstore_clear_refund: 4_800,
+        }
+    }
+}
+
+impl GasModel {
+    /// Build a model from [`AnalyzerConfig::custom_weights`], falling back to
+    /// [`GasModel::default`] for any key that isn't present.
+    pub fn from_config(config: &AnalyzerConfig) -> Self {
+        let default = Self::default();
+        let Some(weights) = &config.custom_weights else {
+            return default;
+        };
+
+        let lookup = |key: &str, fallback: u64| -> u64 {
+            weights
+                .get(key)
+                .copied()
+                .map(|w| w.max(0.0) as u64)
+                .unwrap_or(fallback)
+        };
+
+        Self {
+            sstore: lookup("gas_sstore", default.sstore),
+            sload: lookup("gas_sload", default.sload),
+            external_call: lookup("gas_external_call", default.external_call),
+            loop_base: lookup("gas_loop_base", default.loop_base),
+            loop_unknown_iterations_penalty: lookup(
+                "gas_loop_penalty",
+                default.loop_unknown_iterations_penalty,
+            ),
+            memory_expansion: lookup("gas_memory_expansion", default.memory_expansion),
+            sstore_dirty: lookup("gas_sstore_dirty", default.sstore_dirty),
+            sstore_clear_refund: lookup("gas_sstore_clear_refund", default.sstore_clear_refund),
+        }
+    }
+}
+
+/// How a single storage-write site nets out against the EVM's SSTORE cost
+/// tiers: a cold init (first write to this slot in the function, the most
+/// expensive case), a dirty overwrite (a repeat write to a slot already
+/// touched this function), or a clear (assigning a literal zero, which
+/// earns a refund).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageWriteKind {
+    ColdInit,
+    DirtyOverwrite,
+    Clear,
+}
+
+/// Per-function storage-write accounting, modeled after the EVM
+/// `Substate`'s `refunds_count` on `SSTORE`: writes are classified by how
+/// their slot's value changes, and a slot written more than once inside a
+/// loop is flagged separately since the EVM only ever charges for a slot's
+/// net delta, not each intermediate write.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StorageProfile {
+    /// Total storage-write sites seen in the function.
+    pub writes: u64,
+    /// Writes classified as a clear (nonzero -> zero), which earn a refund.
+    pub refundable_clears: u64,
+    /// Writes to a slot inside a loop that was already written earlier in
+    /// the same function - wasted work beyond the slot's net delta.
+    pub redundant_writes: u64,
+}
+
+/// Estimated gas cost for a single function.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunctionGasEstimate {
+    pub function: String,
+    pub line: usize,
+    pub estimated_gas: u64,
+    pub storage: StorageProfile,
+}
+
+/// Walk `code` and attribute an estimated gas cost to each function body.
+///
+/// Function boundaries are found with the same `fn `/`function ` keyword
+/// scan the rest of this crate's line-based rules use, rather than the AST
+/// parser, so this keeps working even on sources that fail to parse.
+pub fn estimate(code: &str) -> Vec<FunctionGasEstimate> {
+    estimate_with_model(code, &GasModel::default())
+}
+
+/// Accumulator for the function currently being scanned.
+struct InFlight {
+    name: String,
+    line: usize,
+    gas: u64,
+    storage: StorageProfile,
+    /// Gas already spent on storage writes this function, used to cap how
+    /// much of it the refund counter below can claw back.
+    sstore_gas: u64,
+    /// Accrued refund from clears, capped against `sstore_gas` at finalize
+    /// time so a function can't score above its base (non-refund) cost.
+    refund_counter: u64,
+    /// Number of times each slot has been written so far in this function.
+    slot_writes: HashMap<String, u32>,
+}
+
+impl InFlight {
+    fn new(name: String, line: usize) -> Self {
+        Self {
+            name,
+            line,
+            gas: 0,
+            storage: StorageProfile::default(),
+            sstore_gas: 0,
+            refund_counter: 0,
+            slot_writes: HashMap::new(),
+        }
+    }
+
+    fn finish(self) -> FunctionGasEstimate {
+        let refund = self.refund_counter.min(self.sstore_gas);
+        FunctionGasEstimate {
+            function: self.name,
+            line: self.line,
+            estimated_gas: self.gas.saturating_sub(refund),
+            storage: self.storage,
+        }
+    }
+}
+
+/// Same as [`estimate`], but with an explicit (possibly user-overridden) model.
+pub fn estimate_with_model(code: &str, model: &GasModel) -> Vec<FunctionGasEstimate> {
+    let mut estimates = Vec::new();
+
+    let mut current_function: Option<InFlight> = None;
+    let mut in_loop = false;
+
+    for (line_num, raw_line) in code.lines().enumerate() {
+        let line = raw_line.trim();
+
+        if (line.contains("fn ") || line.contains("function ")) && line.contains('(') {
+            if let Some(function) = current_function.take() {
+                estimates.push(function.finish());
+            }
+            let name = line
+                .split_once("function ")
+                .or_else(|| line.split_once("fn "))
+                .and_then(|(_, rest)| rest.split('(').next())
+                .unwrap_or("")
+                .trim()
+                .to_string();
+            current_function = Some(InFlight::new(name, line_num + 1));
+            in_loop = false;
+        }
+
+        if let Some(function) = current_function.as_mut() {
+            if line.contains("for") || line.contains("while") {
+                in_loop = true;
+                function.gas += model.loop_base;
+            }
+
+            if let Some(slot) = storage_slot(line) {
+                record_storage_write(function, model, &slot, line, in_loop);
+            } else if line.contains("storage") {
+                // A bare mention of `storage` with no identifiable slot -
+                // keep the old flat charge rather than dropping it.
+                let cost = if in_loop {
+                    model.sstore * model.loop_unknown_iterations_penalty
+                } else {
+                    model.sstore
+                };
+                function.gas += cost;
+                function.sstore_gas += cost;
+            }
+
+            if line.contains(".call") || line.contains("transfer") || line.contains(".send") {
+                function.gas += model.external_call;
+            }
+            if line.contains("memory") || line.contains("bytes") {
+                function.gas += model.memory_expansion;
+            }
+            if line.contains('}') && !line.contains('{') {
+                in_loop = false;
+            }
+        }
+    }
+
+    if let Some(function) = current_function {
+        estimates.push(function.finish());
+    }
+
+    estimates
+}
+
+/// Classify and charge a single storage-write site against `function`.
+fn record_storage_write(
+    function: &mut InFlight,
+    model: &GasModel,
+    slot: &str,
+    line: &str,
+    in_loop: bool,
+) {
+    let seen_before = function.slot_writes.get(slot).is_some_and(|&n| n > 0);
+    let write_count = function.slot_writes.entry(slot.to_string()).or_insert(0);
+    *write_count += 1;
+    let repeated_in_loop = in_loop && seen_before;
+
+    let kind = if !seen_before {
+        StorageWriteKind::ColdInit
+    } else if assigns_zero(line) {
+        StorageWriteKind::Clear
+    } else {
+        StorageWriteKind::DirtyOverwrite
+    };
+
+    let base_cost = match kind {
+        StorageWriteKind::ColdInit => model.sstore,
+        StorageWriteKind::DirtyOverwrite | StorageWriteKind::Clear => model.sstore_dirty,
+    };
+    let cost = if repeated_in_loop {
+        base_cost * model.loop_unknown_iterations_penalty
+    } else {
+        base_cost
+    };
+
+    function.gas += cost;
+    function.sstore_gas += cost;
+    function.storage.writes += 1;
+
+    if kind == StorageWriteKind::Clear {
+        function.storage.refundable_clears += 1;
+        function.refund_counter += model.sstore_clear_refund;
+    }
+    if repeated_in_loop {
+        function.storage.redundant_writes += 1;
+    }
+}
+
+/// Heuristic check for `identifier[...] = ...` / `identifier -= ...` style
+/// writes, without the `storage` keyword necessarily appearing on the line.
+fn is_state_write(line: &str) -> bool {
+    (line.contains("balances") || line.contains("[msg.sender]"))
+        && (line.contains('=') && !line.contains("=="))
+}
+
+/// Best-effort slot identifier for a storage-write line: the same
+/// assignment-target heuristic [`crate::parser::storage_write_target`] uses
+/// for a plain `count -= 1;` write, falling back to the identifier before
+/// `[` for a mapping-style write like `balances[msg.sender] -= amt;` that
+/// heuristic can't see past the closing bracket.
+fn storage_slot(line: &str) -> Option<String> {
+    if let Some(target) = crate::parser::storage_write_target(line) {
+        return Some(target);
+    }
+    if !is_state_write(line) {
+        return None;
+    }
+    let bracket = line.find('[')?;
+    let ident: String = line[..bracket]
+        .chars()
+        .rev()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect();
+    (!ident.is_empty()).then_some(ident)
+}
+
+/// Whether an assignment's right-hand side is a literal `0`, the EVM's
+/// "clear" case (nonzero -> zero) that earns a gas refund.
+fn assigns_zero(line: &str) -> bool {
+    let assign_ops = ["+=", "-=", "*=", "/=", "="];
+    for op in assign_ops {
+        if let Some(pos) = line.find(op) {
+            if op == "=" && line[pos..].starts_with("==") {
+                continue;
+            }
+            let rhs = line[pos + op.len()..].trim().trim_end_matches(';').trim();
+            return rhs == "0";
+        }
+    }
+    false
+}
+
+/// Derive the `gas_efficiency` metric from a gas profile.
+///
+/// Penalizes two things, averaged across every function: how far its
+/// estimated cost runs over `budget`, and the ratio of wasteful-to-necessary
+/// storage writes (`StorageProfile::redundant_writes` against
+/// `StorageProfile::writes`) - a function can be under budget and still be
+/// inefficient if it rewrites the same slot in a loop.
+pub fn efficiency_score(profile: &[FunctionGasEstimate], budget: u64) -> u8 {
+    if profile.is_empty() {
+        return 100;
+    }
+
+    let total_overage: f32 = profile
+        .iter()
+        .map(|f| {
+            if f.estimated_gas > budget {
+                (f.estimated_gas - budget) as f32 / budget as f32
+            } else {
+                0.0
+            }
+        })
+        .sum();
+    let average_overage = total_overage / profile.len() as f32;
+
+    let total_waste_ratio: f32 = profile
+        .iter()
+        .map(|f| {
+            if f.storage.writes == 0 {
+                0.0
+            } else {
+                f.storage.redundant_writes as f32 / f.storage.writes as f32
+            }
+        })
+        .sum();
+    let average_waste_ratio = total_waste_ratio / profile.len() as f32;
+
+    let penalty = (average_overage + average_waste_ratio) * 100.0;
+    (100.0 - penalty.min(100.0)) as u8
+}
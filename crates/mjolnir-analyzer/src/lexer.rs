@@ -0,0 +1,113 @@
+//! Shared lexing helpers used by both [`crate::parser`] and the rules that
+//! still work over raw text (see [`crate::rules::AnalysisRule::analyze`]).
+//!
+//! The substring checks in the original rules (`code.contains("now")`,
+//! `code.contains("+")`) can't tell a real token from the same bytes inside
+//! a comment, a string literal, or a longer identifier (`nowhere`). The
+//! helpers here fix both problems: [`clean`] blanks out comments/strings
+//! while preserving line/column layout, and [`tokenize`] splits the cleaned
+//! source on identifier/operator boundaries so a rule can match whole
+//! tokens instead of substrings.
+
+/// A single lexical token and the (1-based) source line it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub text: String,
+    pub line: usize,
+}
+
+/// Blank out the contents of `//`, `/* */` comments and `"..."` string
+/// literals while preserving byte offsets and line breaks, so keyword
+/// search and brace-matching never see text from inside them.
+pub fn clean(code: &str) -> String {
+    let mut out = String::with_capacity(code.len());
+    let bytes = code.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'/' if i + 1 < bytes.len() && bytes[i + 1] == b'/' => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    out.push(' ');
+                    i += 1;
+                }
+            }
+            b'/' if i + 1 < bytes.len() && bytes[i + 1] == b'*' => {
+                out.push(' ');
+                out.push(' ');
+                i += 2;
+                while i + 1 < bytes.len() && !(bytes[i] == b'*' && bytes[i + 1] == b'/') {
+                    out.push(if bytes[i] == b'\n' { '\n' } else { ' ' });
+                    i += 1;
+                }
+                if i + 1 < bytes.len() {
+                    out.push(' ');
+                    out.push(' ');
+                    i += 2;
+                }
+            }
+            b'"' => {
+                out.push(' ');
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    out.push(if bytes[i] == b'\n' { '\n' } else { ' ' });
+                    i += 1;
+                }
+                if i < bytes.len() {
+                    out.push(' ');
+                    i += 1;
+                }
+            }
+            b => {
+                out.push(b as char);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+/// Split a single identifier/operator character into its own token, so e.g.
+/// `now` and `nowhere` never collapse into the same substring match, and
+/// `a+b` tokenizes as `a`, `+`, `b` instead of requiring whitespace.
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Tokenize already-[`clean`]ed source into whitespace/identifier/operator
+/// tokens, one token stream per line.
+pub fn tokenize(clean_code: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    for (idx, line) in clean_code.lines().enumerate() {
+        let line_no = idx + 1;
+        let mut current = String::new();
+        let mut current_is_word = false;
+
+        for c in line.chars() {
+            if c.is_whitespace() {
+                if !current.is_empty() {
+                    tokens.push(Token {
+                        text: std::mem::take(&mut current),
+                        line: line_no,
+                    });
+                }
+                continue;
+            }
+            let word_char = is_word_char(c);
+            if !current.is_empty() && word_char != current_is_word {
+                tokens.push(Token {
+                    text: std::mem::take(&mut current),
+                    line: line_no,
+                });
+            }
+            current_is_word = word_char;
+            current.push(c);
+        }
+        if !current.is_empty() {
+            tokens.push(Token {
+                text: current,
+                line: line_no,
+            });
+        }
+    }
+    tokens
+}
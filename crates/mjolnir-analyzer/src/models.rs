@@ -21,6 +21,23 @@ pub struct Issue {
     pub line: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recommendation: Option<String>,
+    /// Precise start position derived from the matching AST node's span.
+    /// `None` when the issue came from a rule that only saw raw text (e.g.
+    /// the line-based fallback path).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<crate::ast::Position>,
+    /// Precise end position derived from the matching AST node's span.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<crate::ast::Position>,
+    /// The id of the rule that produced this issue (see
+    /// [`crate::AnalysisRule::id`]). Stamped by [`crate::Analyzer::analyze`]
+    /// when it collects each rule's output, not by the rule itself, so this
+    /// stays correct even if a rule builds an `Issue` via a shared helper.
+    pub rule_id: String,
+    /// The category of the rule that produced this issue. Stamped
+    /// alongside `rule_id` so `calculate_metrics` can bucket issues by
+    /// category in a single pass instead of re-running every rule.
+    pub category: Category,
 }
 
 /// Metrics calculated during contract analysis
@@ -38,10 +55,13 @@ pub struct AnalysisResults {
     pub score: u8,
     pub metrics: Metrics,
     pub issues: Vec<Issue>,
+    /// Estimated gas cost per function, from [`crate::gas::GasModel`].
+    pub gas_profile: Vec<crate::gas::FunctionGasEstimate>,
 }
 
 /// Category of analysis rules
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Category {
     Security,
     Performance,
@@ -64,8 +84,16 @@ impl Category {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalyzerConfig {
     pub enabled_rules: Vec<String>,
+    /// Weights for the final score (`security`, `performance`,
+    /// `gas_efficiency`, `code_quality`) as well as per-opcode gas cost
+    /// overrides, keyed by the names in [`crate::gas::GasModel`] (e.g.
+    /// `"gas_sstore"`, `"gas_external_call"`).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_weights: Option<HashMap<String, f32>>,
+    /// Gas budget a function is compared against to derive
+    /// `Metrics::gas_efficiency`. Defaults to [`crate::gas::DEFAULT_GAS_BUDGET`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_budget: Option<u64>,
 }
 
 impl Default for AnalyzerConfig {
@@ -73,6 +101,7 @@ impl Default for AnalyzerConfig {
         Self {
             enabled_rules: vec!["all".to_string()],
             custom_weights: None,
+            gas_budget: None,
         }
     }
 }
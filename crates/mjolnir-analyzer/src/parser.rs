@@ -0,0 +1,276 @@
+//! A small recursive-descent parser that turns Solidity-ish source text into
+//! the [`crate::ast`] tree.
+//!
+//! This intentionally does not implement the full Solidity grammar: it
+//! brace-matches `contract { ... }` and `function ... { ... }` blocks, skips
+//! comments and string literals so their contents never get mistaken for
+//! code, and classifies each statement line inside a function body as an
+//! external call, a storage write, or "other". That is enough structure for
+//! rules such as reentrancy detection to reason about ordering within a
+//! single function instead of scanning the whole file for keywords.
+//!
+//! Parsing is best-effort: anything that looks sufficiently malformed (an
+//! unbalanced brace, for example) returns [`ParseError`] so callers can fall
+//! back to the line-based rules instead of producing a misleading tree.
+//!
+//! This is a conscious deviation from a build-time lalrpop-generated
+//! grammar: a hand-written scanner needs no build script or generated-code
+//! step, and the crate only needs enough structure (spans, brace-matched
+//! bodies, per-statement classification) to back
+//! [`crate::AnalysisRule::analyze_ast`] - not a full Solidity grammar.
+//! Revisit if a rule needs structure this approach can't represent
+//! (expressions, full statement grammar).
+
+use crate::ast::{Contract, Function, SourceUnit, Span, Statement};
+
+/// Reason a source file could not be parsed into a [`SourceUnit`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    UnbalancedBraces,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::UnbalancedBraces => write!(f, "unbalanced braces in source"),
+        }
+    }
+}
+
+/// Parse `code` into a [`SourceUnit`], or return [`ParseError`] if the source
+/// is too malformed to produce a reliable tree.
+pub fn parse(code: &str) -> Result<SourceUnit, ParseError> {
+    let clean = crate::lexer::clean(code);
+    let mut contracts = Vec::new();
+
+    let mut search_from = 0;
+    while let Some(rel) = clean[search_from..].find("contract ") {
+        let keyword_start = search_from + rel;
+        let name_start = keyword_start + "contract ".len();
+        let name_end = clean[name_start..]
+            .find(|c: char| c == '{' || c.is_whitespace())
+            .map(|i| name_start + i)
+            .unwrap_or(clean.len());
+        let name = clean[name_start..name_end].trim().to_string();
+
+        let brace_open = match clean[name_end..].find('{') {
+            Some(i) => name_end + i,
+            None => return Err(ParseError::UnbalancedBraces),
+        };
+        let brace_close = find_matching_brace(&clean, brace_open)?;
+
+        let body = &clean[brace_open + 1..brace_close];
+        let body_offset_line = line_of(&clean, brace_open + 1);
+
+        contracts.push(Contract {
+            name,
+            span: Span::single_line(line_of(&clean, keyword_start), 0, 0),
+            functions: parse_functions(body, body_offset_line),
+        });
+
+        search_from = brace_close + 1;
+    }
+
+    Ok(SourceUnit { contracts })
+}
+
+fn parse_functions(body: &str, base_line: usize) -> Vec<Function> {
+    let mut functions = Vec::new();
+    let mut search_from = 0;
+
+    while let Some(rel) = body[search_from..].find("function ") {
+        let keyword_start = search_from + rel;
+        let name_start = keyword_start + "function ".len();
+        let name_end = body[name_start..]
+            .find(|c: char| c == '(' || c.is_whitespace())
+            .map(|i| name_start + i)
+            .unwrap_or(body.len());
+        let name = body[name_start..name_end].trim().to_string();
+
+        let Some(brace_rel) = body[name_end..].find('{') else {
+            // Abstract/interface declaration with no body (e.g. ends in `;`).
+            search_from = name_end;
+            continue;
+        };
+        let brace_open = name_end + brace_rel;
+        // A `;` before the `{` means this isn't actually a function body
+        // (e.g. `function foo(uint x);` in an interface).
+        if body[name_end..brace_open].contains(';') {
+            search_from = name_end;
+            continue;
+        }
+
+        let Ok(brace_close) = find_matching_brace(body, brace_open) else {
+            search_from = name_end;
+            continue;
+        };
+
+        let fn_body = &body[brace_open + 1..brace_close];
+        let start_line = base_line + body[..keyword_start].matches('\n').count();
+        let end_line = base_line + body[..brace_close].matches('\n').count();
+        let signature = &body[name_end..brace_open];
+
+        functions.push(Function {
+            name,
+            span: Span::single_line(start_line, 0, 0).with_end_line(end_line),
+            modifiers: parse_modifiers(signature),
+            is_private: signature.contains("private") || signature.contains("internal"),
+            body: parse_statements(fn_body, base_line + body[..brace_open].matches('\n').count()),
+        });
+
+        search_from = brace_close + 1;
+    }
+
+    functions
+}
+
+/// Known visibility/mutability keywords that appear in the same position as
+/// a modifier name in a Solidity function header; anything else in that
+/// position is treated as a user-defined modifier (e.g. `onlyOwner`).
+const SIGNATURE_KEYWORDS: &[&str] = &[
+    "public", "private", "internal", "external", "view", "pure", "payable", "returns", "override",
+    "virtual",
+];
+
+/// Extract modifier names from a function signature, e.g. `onlyOwner` in
+/// `(uint amount) public onlyOwner returns (bool)`. Only the text after the
+/// parameter list's closing paren is considered, so parameter names never
+/// get mistaken for modifiers; a trailing `returns (...)` clause is dropped
+/// for the same reason.
+fn parse_modifiers(signature: &str) -> Vec<String> {
+    let Some(open) = signature.find('(') else {
+        return Vec::new();
+    };
+
+    let mut depth = 0i32;
+    let mut close = None;
+    for (i, c) in signature[open..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    close = Some(open + i);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    let Some(close) = close else {
+        return Vec::new();
+    };
+
+    let after = &signature[close + 1..];
+    let after = after.split("returns").next().unwrap_or(after);
+
+    after
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .map(str::trim)
+        .filter(|word| !word.is_empty() && !SIGNATURE_KEYWORDS.contains(word))
+        .map(str::to_string)
+        .collect()
+}
+
+fn parse_statements(body: &str, base_line: usize) -> Vec<Statement> {
+    let mut statements = Vec::new();
+
+    for (idx, raw_line) in body.lines().enumerate() {
+        let line = raw_line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let line_no = base_line + idx + 1;
+        let span = Span::single_line(line_no, 0, raw_line.len());
+        let text = line.to_string();
+
+        if line.contains(".call(")
+            || line.contains(".call{")
+            || line.contains(".transfer(")
+            || line.contains(".send(")
+        {
+            statements.push(Statement::ExternalCall { span, text });
+        } else if line.contains("require(msg.sender") || line.contains("assert(msg.sender") {
+            statements.push(Statement::Guard { span, text });
+        } else if let Some(target) = storage_write_target(line) {
+            statements.push(Statement::StorageWrite { target, span, text });
+        } else {
+            statements.push(Statement::Other { span, text });
+        }
+    }
+
+    statements
+}
+
+/// Heuristically extract the identifier being assigned to, e.g.
+/// `balances[msg.sender] -= amount;` -> `Some("balances")`. Returns `None`
+/// for comparisons (`==`), declarations (`uint x =`), and non-assignments.
+pub(crate) fn storage_write_target(line: &str) -> Option<String> {
+    let assign_ops = ["+=", "-=", "*=", "/=", "="];
+    for op in assign_ops {
+        if let Some(pos) = line.find(op) {
+            if op == "=" && line[pos..].starts_with("==") {
+                continue;
+            }
+            let lhs = line[..pos].trim();
+            let ident: String = lhs
+                .chars()
+                .rev()
+                .take_while(|c| c.is_alphanumeric() || *c == '_')
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+                .collect();
+            if !ident.is_empty() && !lhs.starts_with("uint") && !lhs.starts_with("int") {
+                return Some(ident);
+            }
+            // `lhs` ends in `]` for a mapping/array write (e.g.
+            // `balances[msg.sender]`), so the trailing-identifier scan above
+            // finds nothing - fall back to the identifier before the `[`.
+            if let Some(bracket) = lhs.find('[') {
+                let base = lhs[..bracket].trim();
+                let ident: String = base
+                    .chars()
+                    .rev()
+                    .take_while(|c| c.is_alphanumeric() || *c == '_')
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .rev()
+                    .collect();
+                if !ident.is_empty() {
+                    return Some(ident);
+                }
+            }
+        }
+    }
+    None
+}
+
+fn find_matching_brace(text: &str, open_idx: usize) -> Result<usize, ParseError> {
+    let bytes = text.as_bytes();
+    let mut depth = 0usize;
+    for (i, &b) in bytes.iter().enumerate().skip(open_idx) {
+        match b {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    Err(ParseError::UnbalancedBraces)
+}
+
+fn line_of(text: &str, byte_idx: usize) -> usize {
+    text[..byte_idx].matches('\n').count() + 1
+}
+
+impl Span {
+    fn with_end_line(mut self, line: usize) -> Self {
+        self.end.line = line;
+        self
+    }
+}
@@ -0,0 +1,109 @@
+//! Rules that reconcile parsed source against a declared contract ABI (see
+//! [`crate::abi`]). Each rule's `analyze`/`analyze_ast` are no-ops; they
+//! only produce issues through `analyze_abi`, which only runs when an ABI
+//! is supplied alongside the code (see [`crate::Analyzer::analyze_with_abi`]).
+
+use crate::abi::ContractAbi;
+use crate::ast::SourceUnit;
+use crate::models::{Category, Issue};
+use crate::rules::AnalysisRule;
+
+/// Flags ABI-declared payable functions with no visible access-control guard.
+pub struct AbiUnguardedPayableRule {}
+
+impl AnalysisRule for AbiUnguardedPayableRule {
+    fn analyze(&self, _code: &str) -> Vec<Issue> {
+        Vec::new()
+    }
+
+    fn analyze_abi(&self, unit: &SourceUnit, abi: &ContractAbi) -> Vec<Issue> {
+        crate::abi::unguarded_payable_findings(unit, abi)
+    }
+
+    fn category(&self) -> Category {
+        Category::Security
+    }
+
+    fn id(&self) -> &'static str {
+        "abi_unguarded_payable"
+    }
+
+    fn description(&self) -> &'static str {
+        "Detects ABI-declared payable functions with no visible access-control guard"
+    }
+}
+
+/// Flags ABI-declared events that are never emitted in the source.
+pub struct AbiUnemittedEventRule {}
+
+impl AnalysisRule for AbiUnemittedEventRule {
+    fn analyze(&self, _code: &str) -> Vec<Issue> {
+        Vec::new()
+    }
+
+    fn analyze_abi(&self, unit: &SourceUnit, abi: &ContractAbi) -> Vec<Issue> {
+        crate::abi::unemitted_event_findings(unit, abi)
+    }
+
+    fn category(&self) -> Category {
+        Category::CodeQuality
+    }
+
+    fn id(&self) -> &'static str {
+        "abi_unemitted_event"
+    }
+
+    fn description(&self) -> &'static str {
+        "Detects ABI-declared events that are never emitted in source"
+    }
+}
+
+/// Flags ABI-declared functions that are missing from the parsed source.
+pub struct AbiMissingFunctionRule {}
+
+impl AnalysisRule for AbiMissingFunctionRule {
+    fn analyze(&self, _code: &str) -> Vec<Issue> {
+        Vec::new()
+    }
+
+    fn analyze_abi(&self, unit: &SourceUnit, abi: &ContractAbi) -> Vec<Issue> {
+        crate::abi::missing_function_findings(unit, abi)
+    }
+
+    fn category(&self) -> Category {
+        Category::CodeQuality
+    }
+
+    fn id(&self) -> &'static str {
+        "abi_missing_function"
+    }
+
+    fn description(&self) -> &'static str {
+        "Detects functions declared in the ABI but missing from source"
+    }
+}
+
+/// Flags public setters with ABI inputs but no visible input validation.
+pub struct AbiUnvalidatedSetterRule {}
+
+impl AnalysisRule for AbiUnvalidatedSetterRule {
+    fn analyze(&self, _code: &str) -> Vec<Issue> {
+        Vec::new()
+    }
+
+    fn analyze_abi(&self, unit: &SourceUnit, abi: &ContractAbi) -> Vec<Issue> {
+        crate::abi::unvalidated_setter_findings(unit, abi)
+    }
+
+    fn category(&self) -> Category {
+        Category::CodeQuality
+    }
+
+    fn id(&self) -> &'static str {
+        "abi_unvalidated_setter"
+    }
+
+    fn description(&self) -> &'static str {
+        "Detects public setters with ABI inputs but no visible validation"
+    }
+}
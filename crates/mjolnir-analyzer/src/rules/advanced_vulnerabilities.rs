@@ -15,6 +15,10 @@ impl AnalysisRule for DosWithRevertRule {
            (code.contains(".transfer") || code.contains(".send") || code.contains(".call")) {
             
             issues.push(Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::High,
                 message: "Potential DoS with unexpected revert vulnerability".to_string(),
                 line: Some(
@@ -58,6 +62,10 @@ impl AnalysisRule for BlockGasLimitRule {
         if (code.contains("for") || code.contains("while")) && 
            (code.contains("array") || code.contains("mapping") || code.contains("[]")){            
             issues.push(Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::Medium,
                 message: "Potential block gas limit vulnerability with unbounded operation".to_string(),
                 line: Some(
@@ -97,6 +105,10 @@ impl AnalysisRule for ForceSendEtherRule {
     fn analyze(&self, code: &str) -> Vec<Issue> {
         if code.contains("selfdestruct") || code.contains("suicide") {
             vec![Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::Medium,
                 message: "Contract uses selfdestruct which can force-send ether".to_string(),
                 line: Some(
@@ -135,6 +147,10 @@ impl AnalysisRule for SignatureMalleabilityRule {
     fn analyze(&self, code: &str) -> Vec<Issue> {
         if code.contains("ecrecover") && !code.contains("ecrecover(hash, v, r, s)") {
             vec![Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::Medium,
                 message: "Potential signature malleability vulnerability".to_string(),
                 line: Some(
@@ -188,6 +204,10 @@ impl AnalysisRule for WeakRandomnessRule {
             if code.contains(source) && 
                (code.contains("random") || code.contains("lottery") || code.contains("select") || code.contains("winner")) {
                 issues.push(Issue {
+                    start: None,
+                    end: None,
+                    rule_id: String::new(),
+                    category: Category::Security,
                     severity: Severity::High,
                     message: format!("Weak randomness using {}", source),
                     line: Some(
@@ -19,6 +19,10 @@ impl AnalysisRule for MissingVisibilityRule {
                 && !line.contains("external")
             {
                 issues.push(Issue {
+                    start: None,
+                    end: None,
+                    rule_id: String::new(),
+                    category: Category::Security,
                     severity: Severity::Low,
                     message: "Function missing explicit visibility specifier".to_string(),
                     line: Some(i + 1),
@@ -57,6 +61,10 @@ impl AnalysisRule for FloatingPragmaRule {
                 || code.contains("~"))
         {
             vec![Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::Medium,
                 message: "Floating pragma version".to_string(),
                 line: Some(
@@ -101,6 +109,10 @@ impl AnalysisRule for DeprecatedPatternsRule {
         for deprecated in deprecated_functions.iter() {
             if code.contains(deprecated) {
                 issues.push(Issue {
+                    start: None,
+                    end: None,
+                    rule_id: String::new(),
+                    category: Category::Security,
                     severity: Severity::Medium,
                     message: format!("Use of deprecated function or pattern: {}", deprecated),
                     line: Some(
@@ -139,6 +151,10 @@ impl AnalysisRule for TxOriginAuthRule {
     fn analyze(&self, code: &str) -> Vec<Issue> {
         if code.contains("tx.origin") {
             vec![Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::High,
                 message: "Use of tx.origin for authorization".to_string(),
                 line: Some(
@@ -188,6 +204,10 @@ impl AnalysisRule for AssemblyUsageRule {
                 if line.contains("}") {
                     if !has_comment {
                         issues.push(Issue {
+                            start: None,
+                            end: None,
+                            rule_id: String::new(),
+                            category: Category::Security,
                             severity: Severity::Medium,
                             message: "Assembly block without documentation".to_string(),
                             line: Some(assembly_start_line),
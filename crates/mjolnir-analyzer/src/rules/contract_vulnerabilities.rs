@@ -1,5 +1,6 @@
 //! Rules for detecting common smart contract vulnerabilities and anti-patterns
 
+use crate::lexer;
 use crate::models::{Category, Issue, Severity};
 use crate::rules::AnalysisRule;
 
@@ -16,6 +17,10 @@ impl AnalysisRule for DoSVulnerabilityRule {
         if code.contains("for") && 
            (code.contains("transfer") || code.contains(".call")) {
             issues.push(Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::High,
                 message: "DoS vulnerability: unbounded loop with external calls".to_string(),
                 line: Some(
@@ -50,34 +55,41 @@ pub struct IntegerOverflowRule {}
 impl AnalysisRule for IntegerOverflowRule {
     fn analyze(&self, code: &str) -> Vec<Issue> {
         let mut issues = Vec::new();
-        
-        // Check for arithmetic operations without SafeMath or overflow checks
-        if (code.contains("+") || code.contains("-") || code.contains("*") || code.contains("/")) 
-            && !code.contains("SafeMath") 
-            && !code.contains("checked_add") 
-            && !code.contains("checked_sub") 
-            && !code.contains("checked_mul") 
-            && !code.contains("checked_div") {
-            
+
+        // Tokenize comment/string-stripped source so an arithmetic operator
+        // mentioned in a doc comment, or one sitting in a `for` loop counter
+        // (`for (uint i = 0; i < n; i++)`), doesn't get flagged as real
+        // contract arithmetic.
+        let clean = lexer::clean(code);
+        if clean.contains("SafeMath")
+            || clean.contains("checked_add")
+            || clean.contains("checked_sub")
+            || clean.contains("checked_mul")
+            || clean.contains("checked_div")
+        {
+            return issues;
+        }
+
+        let tokens = lexer::tokenize(&clean);
+        let loop_lines = for_loop_header_lines(&clean);
+
+        if let Some(token) = tokens.iter().find(|t| {
+            is_arithmetic_operator(&t.text) && !loop_lines.contains(&t.line)
+        }) {
             issues.push(Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::High,
                 message: "Potential integer overflow/underflow vulnerability".to_string(),
-                line: Some(
-                    code.lines()
-                        .position(|line| 
-                            line.contains("+") || 
-                            line.contains("-") || 
-                            line.contains("*") || 
-                            line.contains("/")
-                        )
-                        .unwrap_or(0) + 1,
-                ),
+                line: Some(token.line),
                 recommendation: Some(
                     "Use SafeMath library or checked arithmetic operations".to_string(),
                 ),
             });
         }
-        
+
         issues
     }
 
@@ -104,6 +116,10 @@ impl AnalysisRule for SelfDestructRule {
             && !code.contains("require(msg.sender") {
             
             vec![Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::High,
                 message: "Unprotected self-destruct functionality".to_string(),
                 line: Some(
@@ -142,18 +158,28 @@ pub struct TimestampDependenceRule {}
 
 impl AnalysisRule for TimestampDependenceRule {
     fn analyze(&self, code: &str) -> Vec<Issue> {
-        if code.contains("block.timestamp") || code.contains("now") {
+        let clean = lexer::clean(code);
+        let tokens = lexer::tokenize(&clean);
+
+        // Match `now` as a whole token so identifiers like `nowhere` or
+        // `renowned` don't trigger a false positive, and `block` + `.` +
+        // `timestamp` as an exact token sequence rather than a substring.
+        let hit = tokens.iter().find(|t| t.text == "now").or_else(|| {
+            tokens.windows(3).find_map(|w| {
+                (w[0].text == "block" && w[1].text == "." && w[2].text == "timestamp")
+                    .then_some(&w[0])
+            })
+        });
+
+        if let Some(token) = hit {
             vec![Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::Medium,
                 message: "Contract logic depends on block timestamp".to_string(),
-                line: Some(
-                    code.lines()
-                        .position(|line| 
-                            line.contains("block.timestamp") || 
-                            line.contains("now")
-                        )
-                        .unwrap_or(0) + 1,
-                ),
+                line: Some(token.line),
                 recommendation: Some(
                     "Avoid using block.timestamp for critical logic as it can be manipulated by miners".to_string(),
                 ),
@@ -186,6 +212,10 @@ impl AnalysisRule for FrontRunningRule {
             && !code.contains("timelock") {
             
             vec![Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::Medium,
                 message: "Potential front-running vulnerability".to_string(),
                 line: Some(
@@ -206,6 +236,10 @@ impl AnalysisRule for FrontRunningRule {
         }
     }
 
+    fn analyze_ast(&self, unit: &crate::ast::SourceUnit) -> Vec<Issue> {
+        crate::taint::front_running_findings(unit)
+    }
+
     fn category(&self) -> Category {
         Category::Security
     }
@@ -232,6 +266,10 @@ impl AnalysisRule for UncheckedReturnRule {
             && !code.contains("assert(") {
             
             issues.push(Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::High,
                 message: "Unchecked return value from low-level call".to_string(),
                 line: Some(
@@ -253,6 +291,10 @@ impl AnalysisRule for UncheckedReturnRule {
             && !code.contains("require(") {
             
             issues.push(Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::Medium,
                 message: "Potential unchecked send/transfer".to_string(),
                 line: Some(
@@ -283,4 +325,20 @@ impl AnalysisRule for UncheckedReturnRule {
     fn description(&self) -> &'static str {
         "Detects unchecked return values from external calls"
     }
+}
+
+fn is_arithmetic_operator(text: &str) -> bool {
+    matches!(text, "+" | "-" | "*" | "/")
+}
+
+/// Line numbers that belong to a `for (...)` loop header, so an increment
+/// like `i++` or `i + 1` in a loop counter isn't mistaken for unchecked
+/// contract arithmetic.
+fn for_loop_header_lines(clean_code: &str) -> Vec<usize> {
+    clean_code
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.trim_start().starts_with("for"))
+        .map(|(idx, _)| idx + 1)
+        .collect()
 }
\ No newline at end of file
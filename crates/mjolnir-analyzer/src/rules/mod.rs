@@ -1,9 +1,12 @@
 //! Analysis rules for smart contracts
 
+mod abi_analysis;
 mod advanced_vulnerabilities;
 mod contract_patterns;
 mod contract_vulnerabilities;
 
+use crate::abi::ContractAbi;
+use crate::ast::SourceUnit;
 use crate::models::{Category, Issue, Severity};
 
 /// Trait for implementing analysis rules
@@ -11,6 +14,28 @@ pub trait AnalysisRule {
     /// Analyze the code and return any issues found
     fn analyze(&self, code: &str) -> Vec<Issue>;
 
+    /// Analyze a parsed [`SourceUnit`] and return any issues found.
+    ///
+    /// Rules that can take advantage of real syntax (e.g. reentrancy
+    /// ordering within a function body) should override this. The default
+    /// implementation returns no issues, so rules that only understand raw
+    /// text keep working unchanged when a source file does parse.
+    fn analyze_ast(&self, _unit: &SourceUnit) -> Vec<Issue> {
+        Vec::new()
+    }
+
+    /// Analyze a parsed [`SourceUnit`] against a declared [`ContractAbi`]
+    /// and return any issues found.
+    ///
+    /// Rules that reconcile source against an external interface
+    /// description - a payable function missing a guard, a declared event
+    /// never emitted - override this. The default implementation returns
+    /// no issues, so rules keep working unchanged when no ABI is supplied
+    /// (see [`crate::Analyzer::analyze_with_abi`]).
+    fn analyze_abi(&self, _unit: &SourceUnit, _abi: &ContractAbi) -> Vec<Issue> {
+        Vec::new()
+    }
+
     /// Get the category this rule belongs to
     fn category(&self) -> Category;
 
@@ -45,6 +70,10 @@ impl AnalysisRule for ReentrancyRule {
 
         if has_transfer && has_state_change_after_call {
             issues.push(Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::High,
                 message: "Potential reentrancy vulnerability in withdraw function".to_string(),
                 line: Some(
@@ -60,6 +89,14 @@ impl AnalysisRule for ReentrancyRule {
         issues
     }
 
+    fn analyze_ast(&self, unit: &SourceUnit) -> Vec<Issue> {
+        // Delegates to the taint pass: an external call followed by a write
+        // to a storage variable that was *read* earlier in the same
+        // function - this is what the text-based version above can't tell
+        // apart from a call and an unrelated write elsewhere in the file.
+        crate::taint::reentrancy_findings(unit)
+    }
+
     fn category(&self) -> Category {
         Category::Security
     }
@@ -80,6 +117,10 @@ impl AnalysisRule for StorageEfficiencyRule {
     fn analyze(&self, code: &str) -> Vec<Issue> {
         if code.contains("storage") && !code.contains("packed") {
             vec![Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::Medium,
                 message: "Inefficient storage usage".to_string(),
                 line: Some(
@@ -117,6 +158,10 @@ impl AnalysisRule for EventEmissionRule {
     fn analyze(&self, code: &str) -> Vec<Issue> {
         if code.contains("state") && !code.contains("emit") {
             vec![Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::Low,
                 message: "Missing event emission after state change".to_string(),
                 line: Some(
@@ -158,6 +203,10 @@ impl AnalysisRule for GasOptimizationRule {
         // Check for expensive operations in loops
         if code.contains("for") && (code.contains("storage") || code.contains("call")) {
             issues.push(Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::Medium,
                 message: "Expensive operation inside loop".to_string(),
                 line: Some(
@@ -175,6 +224,10 @@ impl AnalysisRule for GasOptimizationRule {
         // Check for unnecessary storage reads
         if code.contains("storage") && code.contains("read") && code.contains("loop") {
             issues.push(Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::Low,
                 message: "Multiple storage reads that could be cached".to_string(),
                 line: Some(
@@ -215,6 +268,10 @@ impl AnalysisRule for SecurityBestPracticesRule {
         // Check for unchecked external calls
         if code.contains("call") && !code.contains("require") {
             issues.push(Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::High,
                 message: "Unchecked external call result".to_string(),
                 line: Some(
@@ -233,6 +290,10 @@ impl AnalysisRule for SecurityBestPracticesRule {
             && !code.contains("require(msg.sender")
         {
             issues.push(Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
                 severity: Severity::Medium,
                 message: "Function may lack proper access control".to_string(),
                 line: Some(
@@ -250,6 +311,15 @@ impl AnalysisRule for SecurityBestPracticesRule {
         issues
     }
 
+    fn analyze_ast(&self, unit: &SourceUnit) -> Vec<Issue> {
+        // Precise access-control check: a non-private function that writes
+        // to storage but has no `require(msg.sender == ...)` guard or
+        // access-control modifier anywhere in its body/signature - rather
+        // than the text-based version's blanket "no onlyOwner in the whole
+        // file" check above.
+        crate::taint::access_control_findings(unit)
+    }
+
     fn category(&self) -> Category {
         Category::Security
     }
@@ -263,7 +333,56 @@ impl AnalysisRule for SecurityBestPracticesRule {
     }
 }
 
+/// Rule that surfaces the most expensive functions from the [`crate::gas`]
+/// cost model, rather than pattern-matching keywords directly.
+pub struct GasHotspotRule {
+    /// Gas weights to estimate with, honoring any `AnalyzerConfig.custom_weights`
+    /// overrides (see [`crate::gas::GasModel::from_config`]).
+    pub model: crate::gas::GasModel,
+    /// Gas budget a function's estimate is compared against, honoring any
+    /// `AnalyzerConfig.gas_budget` override.
+    pub budget: u64,
+}
+
+impl AnalysisRule for GasHotspotRule {
+    fn analyze(&self, code: &str) -> Vec<Issue> {
+        crate::gas::estimate_with_model(code, &self.model)
+            .into_iter()
+            .filter(|f| f.estimated_gas > self.budget)
+            .map(|f| Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: Category::Security,
+                severity: Severity::Medium,
+                message: format!(
+                    "Function '{}' is an estimated gas hotspot (~{} gas)",
+                    f.function, f.estimated_gas
+                ),
+                line: Some(f.line),
+                recommendation: Some(
+                    "Reduce storage writes/external calls or move loop bodies off-chain where possible"
+                        .to_string(),
+                ),
+            })
+            .collect()
+    }
+
+    fn category(&self) -> Category {
+        Category::GasEfficiency
+    }
+
+    fn id(&self) -> &'static str {
+        "gas_hotspot"
+    }
+
+    fn description(&self) -> &'static str {
+        "Flags functions whose estimated gas cost exceeds the configured budget"
+    }
+}
+
 // Re-export the vulnerability and pattern rules
+pub use abi_analysis::*;
 pub use advanced_vulnerabilities::*;
 pub use contract_patterns::*;
 pub use contract_vulnerabilities::*;
@@ -296,5 +415,16 @@ pub fn get_default_rules() -> Vec<Box<dyn AnalysisRule>> {
         Box::new(ForceSendEtherRule {}),
         Box::new(SignatureMalleabilityRule {}),
         Box::new(WeakRandomnessRule {}),
+        // Gas cost model rules
+        Box::new(GasHotspotRule {
+            model: crate::gas::GasModel::default(),
+            budget: crate::gas::DEFAULT_GAS_BUDGET,
+        }),
+        // ABI reconciliation rules - silent unless an ABI is supplied via
+        // `Analyzer::analyze_with_abi`
+        Box::new(AbiUnguardedPayableRule {}),
+        Box::new(AbiUnemittedEventRule {}),
+        Box::new(AbiMissingFunctionRule {}),
+        Box::new(AbiUnvalidatedSetterRule {}),
     ]
 }
@@ -0,0 +1,194 @@
+//! A small data-flow pass over the [`crate::ast`] tree: for each function it
+//! tracks whether a value established by one statement (the taint
+//! "source" - a storage read, a tainted authority check, a price/rate
+//! variable) flows into a later, more dangerous statement (the "sink" - a
+//! storage write, an external call) without an intervening guard.
+//!
+//! This is intentionally not a full reaching-definitions analysis - there's
+//! no SSA form or branch-sensitive CFG here, just statement order within a
+//! function body - but it's enough to tell "read, then external call, then
+//! write to the same variable" apart from "write to an unrelated variable
+//! somewhere else in the file", which is all the substring-based rules this
+//! replaces could not do.
+
+use crate::ast::{SourceUnit, Statement};
+use crate::models::{Category, Issue, Severity};
+
+/// Modifier names treated as an access-control guard, in addition to an
+/// inline `require(msg.sender == ...)`/`assert(msg.sender == ...)` statement.
+pub(crate) const GUARD_MODIFIERS: &[&str] = &["onlyOwner", "onlyAdmin", "restricted", "authorized"];
+
+/// Precise reentrancy finding: an external call followed by a write to a
+/// storage variable that was *read* earlier in the same function - the
+/// checks-effects-interactions violation. `Issue::start`/`Issue::end` carry
+/// the taint source (the read) and sink (the write).
+pub fn reentrancy_findings(unit: &SourceUnit) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    for contract in &unit.contracts {
+        for function in &contract.functions {
+            let Some(call_idx) = function
+                .body
+                .iter()
+                .position(|stmt| matches!(stmt, Statement::ExternalCall { .. }))
+            else {
+                continue;
+            };
+
+            let Some(write) = function.body[call_idx + 1..]
+                .iter()
+                .find(|stmt| matches!(stmt, Statement::StorageWrite { .. }))
+            else {
+                continue;
+            };
+
+            let Statement::StorageWrite { target, .. } = write else {
+                unreachable!("filtered above")
+            };
+
+            let Some(source) = function.body[..call_idx]
+                .iter()
+                .find(|stmt| stmt.text().contains(target.as_str()))
+            else {
+                continue;
+            };
+
+            let source_span = source.span();
+            let write_span = write.span();
+            issues.push(Issue {
+                start: Some(source_span.start),
+                end: Some(write_span.end),
+                rule_id: String::new(),
+                category: Category::Security,
+                severity: Severity::High,
+                message: format!(
+                    "Potential reentrancy in '{}': '{}' is read on line {}, then written after an external call on line {}",
+                    function.name, target, source_span.start.line, write_span.start.line
+                ),
+                line: Some(function.body[call_idx].span().start.line),
+                recommendation: Some(
+                    "Implement checks-effects-interactions pattern: perform all state changes before making external calls".to_string(),
+                ),
+            });
+        }
+    }
+
+    issues
+}
+
+/// Access-control finding: a (non-private) function that writes to storage
+/// but is reachable without any `require(msg.sender == ...)` guard or
+/// access-control modifier (`onlyOwner`, ...).
+pub fn access_control_findings(unit: &SourceUnit) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    for contract in &unit.contracts {
+        for function in &contract.functions {
+            if function.is_private {
+                continue;
+            }
+
+            let Some(write) = function
+                .body
+                .iter()
+                .find(|stmt| matches!(stmt, Statement::StorageWrite { .. }))
+            else {
+                continue;
+            };
+
+            let has_guard = function
+                .body
+                .iter()
+                .any(|stmt| matches!(stmt, Statement::Guard { .. }))
+                || function
+                    .modifiers
+                    .iter()
+                    .any(|m| GUARD_MODIFIERS.contains(&m.as_str()));
+
+            if has_guard {
+                continue;
+            }
+
+            let write_span = write.span();
+            issues.push(Issue {
+                start: Some(function.span.start),
+                end: Some(write_span.end),
+                rule_id: String::new(),
+                category: Category::Security,
+                severity: Severity::Medium,
+                message: format!(
+                    "Function '{}' writes to storage on line {} without a visible access-control guard",
+                    function.name, write_span.start.line
+                ),
+                line: Some(write_span.start.line),
+                recommendation: Some(
+                    "Guard state-mutating functions with a require(msg.sender == ...) check or an access-control modifier".to_string(),
+                ),
+            });
+        }
+    }
+
+    issues
+}
+
+/// Front-running finding: a price/rate storage variable that flows into an
+/// external call (a value transfer) within the same function, with no
+/// commit-reveal/timelock safeguard anywhere in the source.
+pub fn front_running_findings(unit: &SourceUnit) -> Vec<Issue> {
+    let has_safeguard = unit.contracts.iter().any(|contract| {
+        contract.functions.iter().any(|function| {
+            function
+                .body
+                .iter()
+                .any(|stmt| stmt.text().contains("commit-reveal") || stmt.text().contains("timelock"))
+        })
+    });
+    if has_safeguard {
+        return Vec::new();
+    }
+
+    let mut issues = Vec::new();
+
+    for contract in &unit.contracts {
+        for function in &contract.functions {
+            let Some(source_idx) = function
+                .body
+                .iter()
+                .position(|stmt| is_price_or_rate(stmt.text()))
+            else {
+                continue;
+            };
+
+            let Some(sink) = function.body[source_idx + 1..]
+                .iter()
+                .find(|stmt| matches!(stmt, Statement::ExternalCall { .. }))
+            else {
+                continue;
+            };
+
+            let source_span = function.body[source_idx].span();
+            let sink_span = sink.span();
+            issues.push(Issue {
+                start: Some(source_span.start),
+                end: Some(sink_span.end),
+                rule_id: String::new(),
+                category: Category::Security,
+                severity: Severity::Medium,
+                message: format!(
+                    "Potential front-running in '{}': a price/rate value from line {} flows into a value transfer on line {}",
+                    function.name, source_span.start.line, sink_span.start.line
+                ),
+                line: Some(sink_span.start.line),
+                recommendation: Some(
+                    "Consider implementing commit-reveal pattern or timelock mechanisms".to_string(),
+                ),
+            });
+        }
+    }
+
+    issues
+}
+
+fn is_price_or_rate(text: &str) -> bool {
+    text.contains("price") || text.contains("rate")
+}
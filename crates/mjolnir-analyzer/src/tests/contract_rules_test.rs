@@ -163,6 +163,7 @@ mod tests {
         let config = AnalyzerConfig {
             enabled_rules: vec!["reentrancy".to_string()],
             custom_weights: None,
+            gas_budget: None,
         };
 
         let analyzer = Analyzer::with_config(config);
@@ -0,0 +1,45 @@
+//! Property tests that generate random Solidity-like token streams and
+//! assert the analyzer never panics and never reports a line number outside
+//! the source - the proptest complement to the honggfuzz target in `fuzz/`,
+//! useful for catching the same class of bug quickly in `cargo test`.
+
+use crate::analyze_contract;
+use proptest::prelude::*;
+
+/// A handful of Solidity keywords/operators that show up across the rules,
+/// shuffled into arbitrary "lines" of source.
+fn token() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just("contract".to_string()),
+        Just("function".to_string()),
+        Just("transfer(".to_string()),
+        Just(".call(".to_string()),
+        Just("storage".to_string()),
+        Just("for".to_string()),
+        Just("require(".to_string()),
+        Just("tx.origin".to_string()),
+        Just("now".to_string()),
+        Just("+".to_string()),
+        Just("=".to_string()),
+        Just("{".to_string()),
+        Just("}".to_string()),
+        "[a-zA-Z_][a-zA-Z0-9_]{0,8}".prop_map(|s| s),
+    ]
+}
+
+fn source() -> impl Strategy<Value = String> {
+    prop::collection::vec(token(), 0..200).prop_map(|tokens| tokens.join(" \n"))
+}
+
+proptest! {
+    #[test]
+    fn never_panics_on_token_soup(code in source()) {
+        let max_line = code.lines().count() + 1;
+        let results = analyze_contract(&code);
+        for issue in results.issues {
+            if let Some(line) = issue.line {
+                prop_assert!((1..=max_line).contains(&line));
+            }
+        }
+    }
+}
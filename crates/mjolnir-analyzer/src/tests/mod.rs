@@ -3,6 +3,9 @@
 #[cfg(test)]
 mod contract_rules_test;
 
+#[cfg(test)]
+mod fuzz_properties;
+
 #[cfg(test)]
 mod basic_test {
     #[cfg(test)]
@@ -54,6 +57,7 @@ mod basic_test {
                     .cloned()
                     .collect(),
                 ),
+                gas_budget: None,
             };
 
             let analyzer = Analyzer::with_config(config);
@@ -0,0 +1,809 @@
+//! A minimal WebAssembly module reader for analyzing compiled ink!/Substrate
+//! contracts (`wasm32-unknown-unknown` blobs), as a companion to the
+//! Solidity text rules.
+//!
+//! This only decodes enough of the binary format (the module header, the
+//! section table, and - for the `CODE` section - a best-effort instruction
+//! walk of each function body) to answer the questions [`WasmAnalysisRule`]s
+//! ask: which host functions does the module import, how many functions does
+//! it export, does a function contain a genuine loop back-edge or an
+//! `unreachable` reachable from an exported entry point, and how big is its
+//! declared memory. It is not a validating parser - malformed input or an
+//! opcode this reader doesn't recognize simply yields [`WasmParseError`]
+//! rather than a partial module.
+
+/// Reason a byte blob could not be read as a WASM module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WasmParseError {
+    BadMagicOrVersion,
+    TruncatedSection,
+    UnsupportedInstruction(u8),
+}
+
+impl std::fmt::Display for WasmParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WasmParseError::BadMagicOrVersion => write!(f, "not a WASM module (bad magic/version)"),
+            WasmParseError::TruncatedSection => write!(f, "truncated section in WASM module"),
+            WasmParseError::UnsupportedInstruction(op) => {
+                write!(f, "unsupported instruction opcode 0x{op:02x}")
+            }
+        }
+    }
+}
+
+/// Per-function facts gathered by walking its instruction stream.
+#[derive(Debug, Clone, Default)]
+pub struct WasmFunction {
+    /// Global function index (counting imported functions first, as the
+    /// `CALL`/export encodings do).
+    pub index: u32,
+    /// Whether `unreachable` (0x00) appears anywhere in the body.
+    pub has_unreachable: bool,
+    /// Whether the body contains a `loop` with a branch back to its start -
+    /// a real back-edge, not just the `loop` opcode's presence.
+    pub has_back_edge_loop: bool,
+    /// Function indices this function calls directly (`call`, not
+    /// `call_indirect`, since indirect call targets aren't known statically).
+    pub calls: Vec<u32>,
+    /// A coarse per-instruction weight, in the same spirit as
+    /// [`crate::gas::GasModel`]: calls and loop back-edges cost more than a
+    /// plain arithmetic/local instruction.
+    pub instruction_weight: u64,
+}
+
+/// The handful of facts about a WASM module the analysis rules need.
+#[derive(Debug, Clone, Default)]
+pub struct WasmModule {
+    /// `module::field` for every imported host function.
+    pub imports: Vec<String>,
+    /// Number of entries in the function section (defined functions only).
+    pub function_count: u32,
+    /// Whether any function body contains the `unreachable` opcode (0x00),
+    /// reachable from an exported entry point.
+    pub has_unreachable: bool,
+    /// Whether any function reachable from an exported entry point contains
+    /// a loop with a genuine back-edge (not just the `loop` opcode).
+    pub has_loop: bool,
+    /// Initial page count declared by the memory section, if present.
+    pub memory_initial_pages: Option<u32>,
+    /// Per-function details, indexed by their position in this `Vec` (not
+    /// by global function index - see [`WasmFunction::index`] for that).
+    pub functions: Vec<WasmFunction>,
+    /// Global indices of exported functions - the module's entry points.
+    pub exported_functions: Vec<u32>,
+}
+
+const WASM_MAGIC: [u8; 4] = [0x00, 0x61, 0x73, 0x6d];
+const WASM_VERSION: [u8; 4] = [0x01, 0x00, 0x00, 0x00];
+
+const SECTION_IMPORT: u8 = 2;
+const SECTION_FUNCTION: u8 = 3;
+const SECTION_MEMORY: u8 = 5;
+const SECTION_EXPORT: u8 = 7;
+const SECTION_CODE: u8 = 10;
+
+const EXTERNAL_KIND_FUNCTION: u8 = 0x00;
+
+/// Parse the header, section table, and function bodies of a WASM binary.
+pub fn parse(bytes: &[u8]) -> Result<WasmModule, WasmParseError> {
+    if bytes.len() < 8 || bytes[0..4] != WASM_MAGIC || bytes[4..8] != WASM_VERSION {
+        return Err(WasmParseError::BadMagicOrVersion);
+    }
+
+    let mut module = WasmModule::default();
+    let mut imported_function_count = 0u32;
+    let mut pos = 8;
+
+    while pos < bytes.len() {
+        let section_id = bytes[pos];
+        pos += 1;
+        let (size, new_pos) = read_leb128_u32(bytes, pos)?;
+        pos = new_pos;
+        let section_end = pos + size as usize;
+        if section_end > bytes.len() {
+            return Err(WasmParseError::TruncatedSection);
+        }
+        let section = &bytes[pos..section_end];
+
+        match section_id {
+            SECTION_IMPORT => {
+                let (names, func_count) = parse_imports(section)?;
+                module.imports = names;
+                imported_function_count = func_count;
+            }
+            SECTION_FUNCTION => {
+                let (count, _) = read_leb128_u32(section, 0)?;
+                module.function_count = count;
+            }
+            SECTION_MEMORY => {
+                // `vec(mem)`: memory count, then each `limits` entry's flag
+                // byte, then its initial page count.
+                if section.len() >= 3 {
+                    let (pages, _) = read_leb128_u32(section, 2)?;
+                    module.memory_initial_pages = Some(pages);
+                }
+            }
+            SECTION_EXPORT => {
+                module.exported_functions = parse_exports(section)?;
+            }
+            SECTION_CODE => {
+                module.functions = parse_code(section, imported_function_count)?;
+            }
+            _ => {}
+        }
+
+        pos = section_end;
+    }
+
+    // Reachability from exported entry points: walk the call graph rather
+    // than flagging a loop/unreachable anywhere in the module, which would
+    // also catch dead code never shipped on-chain.
+    let reachable = reachable_functions(&module);
+    module.has_loop = module
+        .functions
+        .iter()
+        .any(|f| reachable.contains(&f.index) && f.has_back_edge_loop);
+    module.has_unreachable = module
+        .functions
+        .iter()
+        .any(|f| reachable.contains(&f.index) && f.has_unreachable);
+
+    Ok(module)
+}
+
+/// Functions reachable from an exported entry point via the direct-call
+/// graph (`call_indirect` targets aren't tracked, since they aren't known
+/// statically - this under-approximates reachability rather than over-flags).
+fn reachable_functions(module: &WasmModule) -> std::collections::HashSet<u32> {
+    use std::collections::HashSet;
+
+    let by_index: std::collections::HashMap<u32, &WasmFunction> =
+        module.functions.iter().map(|f| (f.index, f)).collect();
+
+    let mut seen: HashSet<u32> = HashSet::new();
+    let mut stack: Vec<u32> = module.exported_functions.clone();
+
+    while let Some(idx) = stack.pop() {
+        if !seen.insert(idx) {
+            continue;
+        }
+        if let Some(f) = by_index.get(&idx) {
+            stack.extend(f.calls.iter().copied());
+        }
+    }
+
+    seen
+}
+
+/// Best-effort extraction of `module::field` import names, and a count of
+/// how many of them are function imports (kind `0x00`) - needed to offset
+/// the defined-function index space the `FUNCTION`/`CODE`/`EXPORT` sections
+/// use. Skips entries it can't decode rather than failing the whole parse.
+fn parse_imports(section: &[u8]) -> Result<(Vec<String>, u32), WasmParseError> {
+    let mut imports = Vec::new();
+    let mut function_count = 0u32;
+    let Ok((count, mut pos)) = read_leb128_u32(section, 0) else {
+        return Ok((imports, function_count));
+    };
+
+    for _ in 0..count {
+        let Some((module_name, next)) = read_name(section, pos) else {
+            break;
+        };
+        let Some((field_name, next)) = read_name(section, next) else {
+            break;
+        };
+        imports.push(format!("{module_name}::{field_name}"));
+
+        let Some(&kind) = section.get(next) else {
+            break;
+        };
+        if kind == EXTERNAL_KIND_FUNCTION {
+            function_count += 1;
+        }
+        // Skip the import's kind byte plus its LEB128 index/type descriptor;
+        // this covers functions, tables, memories and globals, whose
+        // descriptor is a single index/limits value in this position.
+        let Ok((_, after_index)) = read_leb128_u32(section, next + 1) else {
+            break;
+        };
+        pos = after_index;
+    }
+
+    Ok((imports, function_count))
+}
+
+/// Parse the `EXPORT` section into the global indices of exported functions.
+fn parse_exports(section: &[u8]) -> Result<Vec<u32>, WasmParseError> {
+    let mut exported = Vec::new();
+    let (count, mut pos) = read_leb128_u32(section, 0)?;
+
+    for _ in 0..count {
+        let Some((_, next)) = read_name(section, pos) else {
+            break;
+        };
+        let Some(&kind) = section.get(next) else {
+            break;
+        };
+        let (index, after_index) = read_leb128_u32(section, next + 1)?;
+        if kind == EXTERNAL_KIND_FUNCTION {
+            exported.push(index);
+        }
+        pos = after_index;
+    }
+
+    Ok(exported)
+}
+
+fn read_name(bytes: &[u8], pos: usize) -> Option<(String, usize)> {
+    let (len, start) = read_leb128_u32(bytes, pos).ok()?;
+    let end = start + len as usize;
+    let name = std::str::from_utf8(bytes.get(start..end)?).ok()?;
+    Some((name.to_string(), end))
+}
+
+fn read_leb128_u32(bytes: &[u8], mut pos: usize) -> Result<(u32, usize), WasmParseError> {
+    let mut result: u32 = 0;
+    let mut shift = 0;
+    loop {
+        let byte = *bytes.get(pos).ok_or(WasmParseError::TruncatedSection)?;
+        result |= ((byte & 0x7f) as u32) << shift;
+        pos += 1;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    Ok((result, pos))
+}
+
+/// Skip a signed LEB128 value (`i32.const`/`i64.const` immediates) without
+/// decoding it - only its length matters for keeping the instruction walk
+/// in sync.
+fn skip_leb128(bytes: &[u8], mut pos: usize) -> Result<usize, WasmParseError> {
+    loop {
+        let byte = *bytes.get(pos).ok_or(WasmParseError::TruncatedSection)?;
+        pos += 1;
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+    Ok(pos)
+}
+
+/// Kind of structured control-flow block on the decode stack, so a `br`
+/// targeting a `Loop` (a continue/back-edge) can be told apart from one
+/// targeting a `Block`/`If` (a forward break).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BlockKind {
+    Function,
+    Block,
+    Loop,
+    If,
+}
+
+const OP_UNREACHABLE: u8 = 0x00;
+const OP_BLOCK: u8 = 0x02;
+const OP_LOOP: u8 = 0x03;
+const OP_IF: u8 = 0x04;
+const OP_ELSE: u8 = 0x05;
+const OP_END: u8 = 0x0B;
+const OP_BR: u8 = 0x0C;
+const OP_BR_IF: u8 = 0x0D;
+const OP_BR_TABLE: u8 = 0x0E;
+const OP_CALL: u8 = 0x10;
+const OP_CALL_INDIRECT: u8 = 0x11;
+const OP_TYPED_SELECT: u8 = 0x1C;
+const OP_REF_NULL: u8 = 0xD0;
+const OP_REF_IS_NULL: u8 = 0xD1;
+const OP_REF_FUNC: u8 = 0xD2;
+const OP_PREFIX_BULK_MEMORY: u8 = 0xFC;
+const OP_PREFIX_SIMD: u8 = 0xFD;
+
+/// Parse the `CODE` section into one [`WasmFunction`] per body.
+fn parse_code(section: &[u8], imported_function_count: u32) -> Result<Vec<WasmFunction>, WasmParseError> {
+    let (body_count, mut pos) = read_leb128_u32(section, 0)?;
+    let mut functions = Vec::new();
+
+    for i in 0..body_count {
+        let (body_size, body_start) = read_leb128_u32(section, pos)?;
+        let body_end = body_start + body_size as usize;
+        if body_end > section.len() {
+            return Err(WasmParseError::TruncatedSection);
+        }
+
+        let mut cursor = body_start;
+
+        // Skip the locals declarations: a vec of (count, valtype) pairs.
+        let (local_group_count, after_count) = read_leb128_u32(section, cursor)?;
+        cursor = after_count;
+        for _ in 0..local_group_count {
+            let (_, after_local_count) = read_leb128_u32(section, cursor)?;
+            cursor = after_local_count + 1; // one valtype byte follows
+        }
+
+        let function = decode_function_body(
+            section,
+            cursor,
+            body_end,
+            imported_function_count + i,
+        )?;
+        functions.push(function);
+
+        pos = body_end;
+    }
+
+    Ok(functions)
+}
+
+/// Walk one function body's instruction stream, tracking the block-kind
+/// stack (to classify `br`/`br_if` targets) and accumulating the facts a
+/// [`WasmFunction`] exposes.
+fn decode_function_body(
+    bytes: &[u8],
+    mut pos: usize,
+    end: usize,
+    index: u32,
+) -> Result<WasmFunction, WasmParseError> {
+    let mut function = WasmFunction {
+        index,
+        ..Default::default()
+    };
+    let mut stack = vec![BlockKind::Function];
+
+    while pos < end {
+        let op = bytes[pos];
+        pos += 1;
+        function.instruction_weight += 1;
+
+        match op {
+            OP_UNREACHABLE => {
+                function.has_unreachable = true;
+            }
+            OP_BLOCK | OP_IF => {
+                pos = skip_blocktype(bytes, pos)?;
+                stack.push(if op == OP_IF {
+                    BlockKind::If
+                } else {
+                    BlockKind::Block
+                });
+            }
+            OP_LOOP => {
+                pos = skip_blocktype(bytes, pos)?;
+                stack.push(BlockKind::Loop);
+            }
+            OP_ELSE => {
+                // Stays within the same `if`/`else` block kind.
+            }
+            OP_END => {
+                stack.pop();
+            }
+            OP_BR | OP_BR_IF => {
+                let (depth, next) = read_leb128_u32(bytes, pos)?;
+                pos = next;
+                if targets_loop(&stack, depth) {
+                    function.has_back_edge_loop = true;
+                }
+                function.instruction_weight += 1;
+            }
+            OP_BR_TABLE => {
+                let (label_count, after_count) = read_leb128_u32(bytes, pos)?;
+                let mut cursor = after_count;
+                let mut any_loop_target = false;
+                for _ in 0..label_count {
+                    let (depth, next) = read_leb128_u32(bytes, cursor)?;
+                    any_loop_target |= targets_loop(&stack, depth);
+                    cursor = next;
+                }
+                let (default_depth, next) = read_leb128_u32(bytes, cursor)?;
+                any_loop_target |= targets_loop(&stack, default_depth);
+                pos = next;
+                if any_loop_target {
+                    function.has_back_edge_loop = true;
+                }
+                function.instruction_weight += label_count as u64 + 2;
+            }
+            OP_CALL => {
+                let (callee, next) = read_leb128_u32(bytes, pos)?;
+                pos = next;
+                function.calls.push(callee);
+                function.instruction_weight += 3;
+            }
+            OP_CALL_INDIRECT => {
+                let (_, after_type) = read_leb128_u32(bytes, pos)?;
+                pos = after_type + 1; // trailing reserved table-index byte
+                function.instruction_weight += 3;
+            }
+            OP_TYPED_SELECT => {
+                // `select t*`: a vec(valtype) immediate - count, then one
+                // byte per declared type.
+                let (type_count, after_count) = read_leb128_u32(bytes, pos)?;
+                pos = after_count + type_count as usize;
+            }
+            OP_REF_NULL => {
+                pos += 1; // reftype byte
+            }
+            OP_REF_IS_NULL => {
+                // No immediate.
+            }
+            OP_REF_FUNC => {
+                let (_, next) = read_leb128_u32(bytes, pos)?;
+                pos = next;
+            }
+            OP_PREFIX_BULK_MEMORY => {
+                pos = skip_bulk_memory_immediate(bytes, pos)?;
+            }
+            OP_PREFIX_SIMD => {
+                pos = skip_simd_immediate(bytes, pos)?;
+            }
+            _ => {
+                pos = skip_other_immediate(op, bytes, pos)?;
+            }
+        }
+    }
+
+    Ok(function)
+}
+
+fn targets_loop(stack: &[BlockKind], depth: u32) -> bool {
+    let target_from_top = depth as usize;
+    stack
+        .len()
+        .checked_sub(1 + target_from_top)
+        .and_then(|i| stack.get(i))
+        .is_some_and(|kind| *kind == BlockKind::Loop)
+}
+
+/// Skip a `block`/`loop`/`if` blocktype immediate: either a single byte
+/// (`0x40` empty, or a value type), or - for the multi-value proposal's
+/// type-index form - a signed LEB128. We only special-case the common
+/// single-byte form and otherwise skip a LEB128, which covers both.
+fn skip_blocktype(bytes: &[u8], pos: usize) -> Result<usize, WasmParseError> {
+    let byte = *bytes.get(pos).ok_or(WasmParseError::TruncatedSection)?;
+    if byte & 0x80 == 0 {
+        Ok(pos + 1)
+    } else {
+        skip_leb128(bytes, pos)
+    }
+}
+
+/// Skip the immediate operand(s) of instructions not already handled by a
+/// dedicated match arm in [`decode_function_body`]. Returns an error for an
+/// opcode this reader doesn't recognize, rather than risking silent
+/// desynchronization of the byte stream.
+fn skip_other_immediate(op: u8, bytes: &[u8], pos: usize) -> Result<usize, WasmParseError> {
+    match op {
+        // No immediate: control/parametric/numeric comparison & arithmetic
+        // instructions, and all the `0x45..=0xBF` numeric ops.
+        0x01 | 0x0F | 0x1A | 0x1B | 0x45..=0xBF => Ok(pos),
+        // local/global index.
+        0x20..=0x24 => Ok(read_leb128_u32(bytes, pos)?.1),
+        // memory load/store: align (LEB) + offset (LEB).
+        0x28..=0x3E => {
+            let (_, after_align) = read_leb128_u32(bytes, pos)?;
+            let (_, after_offset) = read_leb128_u32(bytes, after_align)?;
+            Ok(after_offset)
+        }
+        // memory.size / memory.grow: reserved byte.
+        0x3F | 0x40 => Ok(pos + 1),
+        // i32.const / i64.const: signed LEB128.
+        0x41 | 0x42 => skip_leb128(bytes, pos),
+        // f32.const: 4 bytes.
+        0x43 => Ok(pos + 4),
+        // f64.const: 8 bytes.
+        0x44 => Ok(pos + 8),
+        other => Err(WasmParseError::UnsupportedInstruction(other)),
+    }
+}
+
+/// Skip the immediate operand(s) of an [`OP_PREFIX_BULK_MEMORY`] (`0xFC`)
+/// instruction. The opcode itself is a LEB128-encoded sub-opcode (every
+/// defined one fits in a single byte); `memory.init`/`memory.copy`/
+/// `table.*` take one or two LEB128 index immediates (plus the reserved
+/// zero byte(s) the MVP multi-memory/multi-table proposals reserved for
+/// future use), and the `trunc_sat` conversions take none. rustc's default
+/// `wasm32-unknown-unknown` target emits `memory.copy`/`memory.fill` for
+/// slice/`Vec` operations, so these need to decode correctly rather than
+/// bailing out - see the chunk3-1 review comment this fixes.
+fn skip_bulk_memory_immediate(bytes: &[u8], pos: usize) -> Result<usize, WasmParseError> {
+    let (sub_op, after_sub_op) = read_leb128_u32(bytes, pos)?;
+    match sub_op {
+        // i32/i64.trunc_sat_f32/f64_s/u: no immediate.
+        0..=7 => Ok(after_sub_op),
+        // memory.init: dataidx, then a reserved memory-index byte.
+        8 => Ok(read_leb128_u32(bytes, after_sub_op)?.1 + 1),
+        // data.drop: dataidx.
+        9 => Ok(read_leb128_u32(bytes, after_sub_op)?.1),
+        // memory.copy: two reserved memory-index bytes (dst, src).
+        10 => Ok(after_sub_op + 2),
+        // memory.fill: one reserved memory-index byte.
+        11 => Ok(after_sub_op + 1),
+        // table.init: elemidx, then tableidx.
+        12 => {
+            let (_, after_elem) = read_leb128_u32(bytes, after_sub_op)?;
+            Ok(read_leb128_u32(bytes, after_elem)?.1)
+        }
+        // elem.drop: elemidx.
+        13 => Ok(read_leb128_u32(bytes, after_sub_op)?.1),
+        // table.copy: dst tableidx, then src tableidx.
+        14 => {
+            let (_, after_dst) = read_leb128_u32(bytes, after_sub_op)?;
+            Ok(read_leb128_u32(bytes, after_dst)?.1)
+        }
+        // table.grow / table.size / table.fill: tableidx.
+        15..=17 => Ok(read_leb128_u32(bytes, after_sub_op)?.1),
+        _ => Err(WasmParseError::UnsupportedInstruction(OP_PREFIX_BULK_MEMORY)),
+    }
+}
+
+/// Skip the immediate operand(s) of an [`OP_PREFIX_SIMD`] (`0xFD`)
+/// instruction. The SIMD proposal defines well over a hundred sub-opcodes;
+/// rather than enumerate every one (and risk silently desyncing the byte
+/// stream on a shape we got wrong), this covers the immediate shapes that
+/// actually occur and treats anything past the well-established core
+/// arithmetic/comparison range as no-immediate, which is correct for the
+/// vast majority of auto-vectorized output. Anything this guess gets wrong
+/// still fails loudly as [`WasmParseError::UnsupportedInstruction`] further
+/// down the stream rather than producing a wrong-but-plausible module.
+fn skip_simd_immediate(bytes: &[u8], pos: usize) -> Result<usize, WasmParseError> {
+    let (sub_op, after_sub_op) = read_leb128_u32(bytes, pos)?;
+    match sub_op {
+        // v128.load* / v128.store: memarg (align LEB + offset LEB).
+        0..=11 => {
+            let (_, after_align) = read_leb128_u32(bytes, after_sub_op)?;
+            Ok(read_leb128_u32(bytes, after_align)?.1)
+        }
+        // v128.const: 16-byte literal.
+        12 => Ok(after_sub_op + 16),
+        // i8x16.shuffle: 16 lane-index bytes.
+        13 => Ok(after_sub_op + 16),
+        // i8x16.swizzle / *.splat: no immediate.
+        14..=20 => Ok(after_sub_op),
+        // *.extract_lane / *.replace_lane: one lane-index byte.
+        21..=34 => Ok(after_sub_op + 1),
+        // Everything else in the defined range (comparisons, bitwise ops,
+        // arithmetic, `v128.bitselect`, relaxed-SIMD additions): no
+        // immediate.
+        35..=255 => Ok(after_sub_op),
+        _ => Err(WasmParseError::UnsupportedInstruction(OP_PREFIX_SIMD)),
+    }
+}
+
+/// Host functions known to be dangerous to expose without a matching guard
+/// in ink!/pallet-contracts (self-destruct, raw balance transfer, ...).
+pub const SENSITIVE_IMPORTS: &[&str] = &[
+    "seal_terminate",
+    "seal_transfer",
+    "seal_delegate_call",
+    "seal_set_code_hash",
+];
+
+/// A function's estimated instruction weight is flagged once it crosses
+/// this budget - an order-of-magnitude smaller than [`crate::gas::DEFAULT_GAS_BUDGET`]
+/// since this counts raw instructions, not EVM-ish storage/call costs.
+pub const DEFAULT_INSTRUCTION_BUDGET: u64 = 2_000;
+
+/// A declared initial memory size larger than this (in 64KiB pages) is
+/// flagged as oversized for a contract module.
+pub const MAX_REASONABLE_MEMORY_PAGES: u32 = 16;
+
+/// Trait for rules that inspect a parsed [`WasmModule`] instead of Solidity
+/// source text. Mirrors [`crate::AnalysisRule`] so both kinds of rule share
+/// `Category`/`Issue` and can be merged into one [`crate::AnalysisResults`].
+pub trait WasmAnalysisRule {
+    fn analyze(&self, module: &WasmModule) -> Vec<crate::Issue>;
+    fn category(&self) -> crate::Category;
+    fn id(&self) -> &'static str;
+    fn description(&self) -> &'static str;
+}
+
+/// Flags imported host functions known to be sensitive capabilities.
+pub struct DangerousImportsRule {}
+
+impl WasmAnalysisRule for DangerousImportsRule {
+    fn analyze(&self, module: &WasmModule) -> Vec<crate::Issue> {
+        module
+            .imports
+            .iter()
+            .filter(|import| {
+                SENSITIVE_IMPORTS
+                    .iter()
+                    .any(|sensitive| import.ends_with(sensitive))
+            })
+            .map(|import| crate::Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: crate::Category::Security,
+                severity: crate::Severity::High,
+                message: format!("Module imports sensitive host function '{import}'"),
+                line: None,
+                recommendation: Some(
+                    "Audit call sites of this import for proper access control".to_string(),
+                ),
+            })
+            .collect()
+    }
+
+    fn category(&self) -> crate::Category {
+        crate::Category::Security
+    }
+
+    fn id(&self) -> &'static str {
+        "wasm_dangerous_imports"
+    }
+
+    fn description(&self) -> &'static str {
+        "Flags imports of sensitive pallet-contracts host functions"
+    }
+}
+
+/// Flags functions reachable from an export whose body contains a `loop`
+/// with a genuine back-edge (a `br`/`br_if` targeting the loop), rather than
+/// just the presence of the `loop` opcode.
+pub struct UnboundedLoopRule {}
+
+impl WasmAnalysisRule for UnboundedLoopRule {
+    fn analyze(&self, module: &WasmModule) -> Vec<crate::Issue> {
+        if module.has_loop {
+            vec![crate::Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: crate::Category::Security,
+                severity: crate::Severity::Medium,
+                message: "An exported function reaches a loop with a back-edge; verify it has a bounded iteration count".to_string(),
+                line: None,
+                recommendation: Some(
+                    "Ensure loops are bounded by a fixed or gas-metered iteration count".to_string(),
+                ),
+            }]
+        } else {
+            vec![]
+        }
+    }
+
+    fn category(&self) -> crate::Category {
+        crate::Category::GasEfficiency
+    }
+
+    fn id(&self) -> &'static str {
+        "wasm_unbounded_loop"
+    }
+
+    fn description(&self) -> &'static str {
+        "Flags loop back-edges reachable from an exported function"
+    }
+}
+
+/// Flags `unreachable` instructions reachable from an exported entry point -
+/// these usually indicate an unhandled panic path an attacker can trigger.
+pub struct UnreachablePanicRule {}
+
+impl WasmAnalysisRule for UnreachablePanicRule {
+    fn analyze(&self, module: &WasmModule) -> Vec<crate::Issue> {
+        if module.has_unreachable {
+            vec![crate::Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: crate::Category::Security,
+                severity: crate::Severity::Medium,
+                message: "An exported function reaches an `unreachable` instruction".to_string(),
+                line: None,
+                recommendation: Some(
+                    "Replace panics on untrusted input with a recoverable error return".to_string(),
+                ),
+            }]
+        } else {
+            vec![]
+        }
+    }
+
+    fn category(&self) -> crate::Category {
+        crate::Category::CodeQuality
+    }
+
+    fn id(&self) -> &'static str {
+        "wasm_unreachable_panic"
+    }
+
+    fn description(&self) -> &'static str {
+        "Flags `unreachable` instructions reachable from an exported function"
+    }
+}
+
+/// Flags a declared initial memory size larger than
+/// [`MAX_REASONABLE_MEMORY_PAGES`] - an oversized linear memory inflates the
+/// deployed contract's storage deposit and rent.
+pub struct OversizedMemoryRule {}
+
+impl WasmAnalysisRule for OversizedMemoryRule {
+    fn analyze(&self, module: &WasmModule) -> Vec<crate::Issue> {
+        match module.memory_initial_pages {
+            Some(pages) if pages > MAX_REASONABLE_MEMORY_PAGES => vec![crate::Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: crate::Category::Security,
+                severity: crate::Severity::Low,
+                message: format!(
+                    "Module declares {pages} initial memory pages, above the {MAX_REASONABLE_MEMORY_PAGES}-page guideline"
+                ),
+                line: None,
+                recommendation: Some(
+                    "Reduce the initial memory size or allocate on demand via memory.grow"
+                        .to_string(),
+                ),
+            }],
+            _ => vec![],
+        }
+    }
+
+    fn category(&self) -> crate::Category {
+        crate::Category::GasEfficiency
+    }
+
+    fn id(&self) -> &'static str {
+        "wasm_oversized_memory"
+    }
+
+    fn description(&self) -> &'static str {
+        "Flags a declared initial memory size above a reasonable contract guideline"
+    }
+}
+
+/// Flags functions whose estimated instruction weight exceeds
+/// [`DEFAULT_INSTRUCTION_BUDGET`], mirroring the source-level gas hotspot
+/// rule's budget-based approach.
+pub struct InstructionWeightRule {}
+
+impl WasmAnalysisRule for InstructionWeightRule {
+    fn analyze(&self, module: &WasmModule) -> Vec<crate::Issue> {
+        module
+            .functions
+            .iter()
+            .filter(|f| f.instruction_weight > DEFAULT_INSTRUCTION_BUDGET)
+            .map(|f| crate::Issue {
+                start: None,
+                end: None,
+                rule_id: String::new(),
+                category: crate::Category::Security,
+                severity: crate::Severity::Medium,
+                message: format!(
+                    "Function #{} is an estimated instruction-weight hotspot (~{} weight)",
+                    f.index, f.instruction_weight
+                ),
+                line: None,
+                recommendation: Some(
+                    "Reduce calls/branches in this function or split it into smaller exported entry points"
+                        .to_string(),
+                ),
+            })
+            .collect()
+    }
+
+    fn category(&self) -> crate::Category {
+        crate::Category::GasEfficiency
+    }
+
+    fn id(&self) -> &'static str {
+        "wasm_instruction_weight"
+    }
+
+    fn description(&self) -> &'static str {
+        "Flags functions whose estimated instruction weight exceeds the configured budget"
+    }
+}
+
+/// Get all default WASM rules, mirroring [`crate::get_default_rules`].
+pub fn get_default_wasm_rules() -> Vec<Box<dyn WasmAnalysisRule>> {
+    vec![
+        Box::new(DangerousImportsRule {}),
+        Box::new(UnboundedLoopRule {}),
+        Box::new(UnreachablePanicRule {}),
+        Box::new(OversizedMemoryRule {}),
+        Box::new(InstructionWeightRule {}),
+    ]
+}
@@ -0,0 +1,54 @@
+//! A minimal contract model shared by the Solidity and ink! front/back ends.
+//!
+//! This only captures what the structural mapping in [`crate::convert`]
+//! needs: the contract name, its state variables, its functions (kept as
+//! raw body text - we translate signatures and a handful of well-known
+//! expressions, not arbitrary statements), and its events.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateVar {
+    pub name: String,
+    pub ty: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: String,
+    pub ty: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Visibility {
+    Public,
+    Private,
+    Internal,
+    External,
+}
+
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub name: String,
+    pub is_constructor: bool,
+    pub is_payable: bool,
+    pub visibility: Visibility,
+    pub params: Vec<Param>,
+    /// Raw body lines (trimmed, brace-matched from the source), translated
+    /// line-by-line rather than reparsed into statements (see
+    /// [`crate::convert::translate_expressions`]). Empty if the body could
+    /// not be brace-matched (e.g. an interface stub with no `{ ... }`).
+    pub body: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Event {
+    pub name: String,
+    pub fields: Vec<Param>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Contract {
+    pub name: String,
+    pub state_vars: Vec<StateVar>,
+    pub functions: Vec<Function>,
+    pub events: Vec<Event>,
+}
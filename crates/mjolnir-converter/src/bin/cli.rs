@@ -1,6 +1,8 @@
-use mjolnir_converter::{ContractConverter, ConversionConfig, ConversionError, ConversionResult};
+use mjolnir_converter::convert::{emit_ink, emit_solidity, parse_ink, parse_solidity};
+use mjolnir_converter::{ContractConverter, ContractType, ConversionConfig, ConversionError, ConversionResult};
 use serde::Deserialize;
 use std::io::{self, Read};
+use std::process::Command;
 
 #[derive(Deserialize)]
 struct Request {
@@ -16,21 +18,66 @@ impl ContractConverter for DefaultConverter {
         code: &str,
         config: &ConversionConfig,
     ) -> Result<ConversionResult, ConversionError> {
-        // TODO: Implement actual conversion logic
-        // This is a placeholder that just returns the input code
+        let source_type = self
+            .detect_type(code)
+            .ok_or_else(|| ConversionError::ParseError("could not detect contract dialect".into()))?;
+
+        let converted_code = match (&source_type, &config.target) {
+            (ContractType::Solidity, ContractType::Ink) => emit_ink(&parse_solidity(code)?),
+            (ContractType::Ink, ContractType::Solidity) => emit_solidity(&parse_ink(code)?),
+            (ContractType::Solidity, ContractType::Solidity)
+            | (ContractType::Ink, ContractType::Ink) => code.to_string(),
+        };
+
+        let compilation_output = if config.optimize.unwrap_or(false) {
+            try_compile(&converted_code, &config.target)
+        } else {
+            None
+        };
+
         Ok(ConversionResult {
-            converted_code: code.to_string(),
+            converted_code,
             target_type: config.target.clone(),
-            compilation_output: None,
+            compilation_output,
         })
     }
 
-    fn detect_type(&self, _code: &str) -> Option<mjolnir_converter::ContractType> {
-        // TODO: Implement contract type detection
-        None
+    fn detect_type(&self, code: &str) -> Option<ContractType> {
+        if code.contains("pragma solidity") || code.contains("contract ") {
+            Some(ContractType::Solidity)
+        } else if code.contains("#[ink::contract]") || code.contains("#[ink(storage)]") {
+            Some(ContractType::Ink)
+        } else {
+            None
+        }
     }
 }
 
+/// Best-effort compile of the converted output, used only when the caller
+/// asked for `optimize`. Missing toolchains (no `solc` / `cargo-contract` on
+/// PATH) are not a hard error - we simply return no compilation output.
+fn try_compile(code: &str, target: &ContractType) -> Option<String> {
+    let dir = std::env::temp_dir();
+    let (path, program, args): (_, _, Vec<&str>) = match target {
+        ContractType::Solidity => (dir.join("mjolnir_convert_out.sol"), "solc", vec!["--bin"]),
+        ContractType::Ink => (
+            dir.join("mjolnir_convert_out.rs"),
+            "cargo",
+            vec!["contract", "check"],
+        ),
+    };
+
+    std::fs::write(&path, code).ok()?;
+
+    let output = Command::new(program).args(&args).arg(&path).output().ok()?;
+
+    Some(String::from_utf8_lossy(if output.status.success() {
+        &output.stdout
+    } else {
+        &output.stderr
+    }).into_owned())
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Read JSON input from stdin
     let mut input = String::new();
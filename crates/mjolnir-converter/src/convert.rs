@@ -0,0 +1,523 @@
+//! Structural Solidity <-> ink! translation.
+//!
+//! Both directions go through the shared [`crate::ast::Contract`]: parse the
+//! source dialect into that model, then emit the target dialect from it.
+//! The mapping covers the constructs that show up in the vast majority of
+//! small/medium contracts (state variables, `public`/`external` functions,
+//! constructors, events, and the handful of Solidity globals that have a
+//! direct ink! equivalent); anything else is carried across as a best-effort
+//! literal line so the output still compiles-adjacent rather than silently
+//! dropping code.
+
+use crate::ast::{Contract, Event, Function, Param, StateVar, Visibility};
+use crate::ConversionError;
+
+/// Parse a (simplified) Solidity contract into the shared AST.
+pub fn parse_solidity(code: &str) -> Result<Contract, ConversionError> {
+    let name = extract_between(code, "contract ", "{")
+        .ok_or_else(|| ConversionError::ParseError("no `contract` declaration found".into()))?
+        .trim()
+        .split_whitespace()
+        .next()
+        .unwrap_or("Contract")
+        .to_string();
+
+    let mut state_vars = Vec::new();
+    let mut functions = Vec::new();
+    let mut events = Vec::new();
+
+    let lines: Vec<&str> = code.lines().collect();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i].trim();
+
+        if line.starts_with("event ") {
+            let sig = line.trim_start_matches("event ").trim_end_matches(';');
+            if let Some((ev_name, params)) = parse_signature(sig) {
+                events.push(Event {
+                    name: ev_name,
+                    fields: params
+                        .into_iter()
+                        .map(|p| Param {
+                            name: p.name,
+                            ty: solidity_type_to_ink(&p.ty),
+                        })
+                        .collect(),
+                });
+            }
+        } else if line.starts_with("function ") || line.starts_with("constructor") {
+            let is_constructor = line.starts_with("constructor");
+            let sig_src = if is_constructor {
+                line.trim_start_matches("constructor")
+            } else {
+                line.trim_start_matches("function ")
+            };
+            let (fn_name, params) = if is_constructor {
+                ("new".to_string(), parse_signature(&format!("ctor{sig_src}")).map(|(_, p)| p).unwrap_or_default())
+            } else {
+                parse_signature(sig_src).unwrap_or(("unknown".to_string(), Vec::new()))
+            };
+
+            let (body, consumed) = brace_matched_body(&lines, i);
+
+            functions.push(Function {
+                name: fn_name,
+                is_constructor,
+                is_payable: line.contains("payable"),
+                visibility: if line.contains("public") || is_constructor {
+                    Visibility::Public
+                } else if line.contains("external") {
+                    Visibility::External
+                } else if line.contains("internal") {
+                    Visibility::Internal
+                } else {
+                    Visibility::Private
+                },
+                params: params
+                    .into_iter()
+                    .map(|p| Param {
+                        name: p.name,
+                        ty: solidity_type_to_ink(&p.ty),
+                    })
+                    .collect(),
+                body,
+            });
+
+            i += consumed;
+            continue;
+        } else if (line.contains("mapping(") || is_solidity_state_decl(line))
+            && line.ends_with(';')
+        {
+            if let Some(var) = parse_state_var(line) {
+                state_vars.push(StateVar {
+                    name: var.name,
+                    ty: solidity_type_to_ink(&var.ty),
+                });
+            }
+        }
+
+        i += 1;
+    }
+
+    Ok(Contract {
+        name,
+        state_vars,
+        functions,
+        events,
+    })
+}
+
+/// Walk forward from `lines[start]` (the function/constructor header),
+/// brace-counting to find the matching close, and return its body lines
+/// (trimmed, braces excluded) along with how many lines - header included -
+/// to advance past. Returns an empty body and advances by 1 if the header
+/// has no `{` (e.g. an interface stub ending in `;`).
+fn brace_matched_body(lines: &[&str], start: usize) -> (Vec<String>, usize) {
+    let mut depth =
+        lines[start].matches('{').count() as i32 - lines[start].matches('}').count() as i32;
+    if depth <= 0 {
+        return (Vec::new(), 1);
+    }
+
+    let mut body = Vec::new();
+    let mut j = start + 1;
+    while j < lines.len() && depth > 0 {
+        depth += lines[j].matches('{').count() as i32 - lines[j].matches('}').count() as i32;
+        if depth > 0 {
+            body.push(lines[j].trim().to_string());
+        }
+        j += 1;
+    }
+
+    (body, j - start)
+}
+
+/// Parse a (simplified) ink! module into the shared AST.
+pub fn parse_ink(code: &str) -> Result<Contract, ConversionError> {
+    let name = extract_between(code, "pub struct ", "{")
+        .or_else(|| extract_between(code, "mod ", "{"))
+        .ok_or_else(|| {
+            ConversionError::ParseError("no `#[ink(storage)]` struct or module found".into())
+        })?
+        .trim()
+        .split_whitespace()
+        .next()
+        .unwrap_or("Contract")
+        .to_string();
+
+    let mut state_vars = Vec::new();
+    let mut functions = Vec::new();
+    let mut events = Vec::new();
+
+    let lines: Vec<&str> = code.lines().collect();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i].trim();
+
+        if line.starts_with("pub struct ") && !line.contains('{') {
+            i += 1;
+            continue;
+        }
+        if let Some(var) = parse_ink_field(line) {
+            state_vars.push(StateVar {
+                name: var.name,
+                ty: ink_type_to_solidity(&var.ty),
+            });
+        } else if line.starts_with("pub fn ") {
+            let is_constructor = line.contains("new(") || line.contains("fn new");
+            let sig = line.trim_start_matches("pub fn ");
+            if let Some((fn_name, params)) = parse_signature(sig) {
+                let (body, consumed) = brace_matched_body(&lines, i);
+                functions.push(Function {
+                    name: fn_name,
+                    is_constructor,
+                    is_payable: line.contains("payable"),
+                    visibility: Visibility::Public,
+                    params: params
+                        .into_iter()
+                        .filter(|p| p.name != "self" && p.name != "&mut self")
+                        .map(|p| Param {
+                            name: p.name,
+                            ty: ink_type_to_solidity(&p.ty),
+                        })
+                        .collect(),
+                    body,
+                });
+                i += consumed;
+                continue;
+            }
+        } else if line.starts_with("pub struct ") {
+            if let Some((ev_name, _)) = parse_signature(line.trim_start_matches("pub struct ")) {
+                events.push(Event {
+                    name: ev_name,
+                    fields: Vec::new(),
+                });
+            }
+        }
+
+        i += 1;
+    }
+
+    Ok(Contract {
+        name,
+        state_vars,
+        functions,
+        events,
+    })
+}
+
+/// Emit an ink! module from the shared AST.
+pub fn emit_ink(contract: &Contract) -> String {
+    let mut out = String::new();
+    out.push_str("#[ink::contract]\n");
+    out.push_str(&format!("mod {} {{\n", to_snake_case(&contract.name)));
+
+    for event in &contract.events {
+        out.push_str("    #[ink(event)]\n");
+        out.push_str(&format!("    pub struct {} {{\n", event.name));
+        for field in &event.fields {
+            out.push_str(&format!("        pub {}: {},\n", field.name, field.ty));
+        }
+        out.push_str("    }\n\n");
+    }
+
+    out.push_str("    #[ink(storage)]\n");
+    out.push_str(&format!("    pub struct {} {{\n", contract.name));
+    for var in &contract.state_vars {
+        out.push_str(&format!("        {}: {},\n", var.name, var.ty));
+    }
+    out.push_str("    }\n\n");
+
+    out.push_str(&format!("    impl {} {{\n", contract.name));
+    for function in &contract.functions {
+        let params = render_ink_params(&function.params);
+        if function.is_constructor {
+            out.push_str("        #[ink(constructor)]\n");
+            out.push_str(&format!("        pub fn new({}) -> Self {{\n", params));
+        } else {
+            out.push_str(&format!(
+                "        #[ink(message{})]\n",
+                if function.is_payable { ", payable" } else { "" }
+            ));
+            let self_param = if params.is_empty() {
+                "&mut self".to_string()
+            } else {
+                format!("&mut self, {}", params)
+            };
+            out.push_str(&format!(
+                "        pub fn {}({}) {{\n",
+                function.name, self_param
+            ));
+        }
+        out.push_str(&render_body(&function.body, "            "));
+        out.push_str("        }\n\n");
+    }
+    out.push_str("    }\n");
+    out.push_str("}\n");
+
+    translate_expressions(&out, Direction::SolidityToInk)
+}
+
+/// Emit Solidity source from the shared AST.
+pub fn emit_solidity(contract: &Contract) -> String {
+    let mut out = String::new();
+    out.push_str("pragma solidity ^0.8.0;\n\n");
+    out.push_str(&format!("contract {} {{\n", contract.name));
+
+    for var in &contract.state_vars {
+        out.push_str(&format!("    {} {};\n", var.ty, var.name));
+    }
+    if !contract.state_vars.is_empty() {
+        out.push('\n');
+    }
+
+    for event in &contract.events {
+        out.push_str(&format!(
+            "    event {}({});\n",
+            event.name,
+            event
+                .fields
+                .iter()
+                .map(|f| format!("{} {}", f.ty, f.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    if !contract.events.is_empty() {
+        out.push('\n');
+    }
+
+    for function in &contract.functions {
+        let params = render_solidity_params(&function.params);
+        if function.is_constructor {
+            out.push_str(&format!("    constructor({}) {{\n", params));
+        } else {
+            out.push_str(&format!(
+                "    function {}({}) public{} {{\n",
+                function.name,
+                params,
+                if function.is_payable { " payable" } else { "" }
+            ));
+        }
+        out.push_str(&render_body(&function.body, "        "));
+        out.push_str("    }\n\n");
+    }
+
+    out.push_str("}\n");
+
+    translate_expressions(&out, Direction::InkToSolidity)
+}
+
+enum Direction {
+    SolidityToInk,
+    InkToSolidity,
+}
+
+/// Rewrite the handful of Solidity globals / ink! environment calls that
+/// have a direct one-line equivalent in the other dialect.
+fn translate_expressions(code: &str, direction: Direction) -> String {
+    let code = match direction {
+        Direction::SolidityToInk => code
+            .replace("msg.sender", "self.env().caller()")
+            .replace("msg.value", "self.env().transferred_value()"),
+        Direction::InkToSolidity => code
+            .replace("self.env().caller()", "msg.sender")
+            .replace("self.env().transferred_value()", "msg.value"),
+    };
+
+    code.lines()
+        .map(|line| match direction {
+            Direction::SolidityToInk => translate_require(line),
+            Direction::InkToSolidity => translate_assert(line),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        + "\n"
+}
+
+/// Map a `require(cond, "msg")` / `require(cond)` call to the ink! idiom.
+fn translate_require(line: &str) -> String {
+    if let Some(args) = extract_between(line, "require(", ")") {
+        return format!("{}assert!({});", &line[..line.find("require(").unwrap()], args);
+    }
+    line.to_string()
+}
+
+/// Map an ink! `assert!(cond, "msg")` back to Solidity's `require`.
+fn translate_assert(line: &str) -> String {
+    if let Some(args) = extract_between(line, "assert!(", ")") {
+        return format!("{}require({});", &line[..line.find("assert!(").unwrap()], args);
+    }
+    line.to_string()
+}
+
+fn solidity_type_to_ink(ty: &str) -> String {
+    let ty = ty.trim();
+    if let Some(inner) = ty
+        .strip_prefix("mapping(")
+        .and_then(|s| s.strip_suffix(')'))
+    {
+        if let Some((k, v)) = inner.split_once("=>") {
+            return format!(
+                "ink::storage::Mapping<{}, {}>",
+                solidity_type_to_ink(k.trim()),
+                solidity_type_to_ink(v.trim())
+            );
+        }
+    }
+    match ty {
+        "uint256" | "uint" => "u128".to_string(),
+        "uint128" => "u128".to_string(),
+        "uint64" => "u64".to_string(),
+        "uint32" => "u32".to_string(),
+        "uint8" => "u8".to_string(),
+        "int256" | "int" => "i128".to_string(),
+        "bool" => "bool".to_string(),
+        "address" => "AccountId".to_string(),
+        "string" | "string memory" | "string calldata" => "String".to_string(),
+        "bytes" | "bytes memory" => "Vec<u8>".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn ink_type_to_solidity(ty: &str) -> String {
+    let ty = ty.trim();
+    if let Some(inner) = ty
+        .strip_prefix("ink::storage::Mapping<")
+        .and_then(|s| s.strip_suffix('>'))
+    {
+        if let Some((k, v)) = inner.split_once(',') {
+            return format!(
+                "mapping({} => {})",
+                ink_type_to_solidity(k.trim()),
+                ink_type_to_solidity(v.trim())
+            );
+        }
+    }
+    match ty {
+        "u128" => "uint256".to_string(),
+        "u64" => "uint64".to_string(),
+        "u32" => "uint32".to_string(),
+        "u8" => "uint8".to_string(),
+        "i128" => "int256".to_string(),
+        "bool" => "bool".to_string(),
+        "AccountId" => "address".to_string(),
+        "String" => "string".to_string(),
+        "Vec<u8>" => "bytes".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn is_solidity_state_decl(line: &str) -> bool {
+    let known_types = [
+        "uint256", "uint", "uint128", "uint64", "uint32", "uint8", "int256", "int", "bool",
+        "address", "string", "bytes",
+    ];
+    known_types
+        .iter()
+        .any(|t| line.starts_with(t) && !line.contains('(') && !line.contains("function"))
+}
+
+fn parse_state_var(line: &str) -> Option<StateVar> {
+    let line = line.trim_end_matches(';').trim();
+    let mut parts = line.rsplitn(2, ' ');
+    let name = parts.next()?.trim_start_matches("public").trim().to_string();
+    let ty = parts.next()?.trim().to_string();
+    if name.is_empty() || ty.is_empty() {
+        return None;
+    }
+    Some(StateVar { name, ty })
+}
+
+fn parse_ink_field(line: &str) -> Option<StateVar> {
+    let line = line.trim().trim_end_matches(',');
+    let (name, ty) = line.split_once(':')?;
+    let name = name.trim().to_string();
+    if name.is_empty() || name.starts_with("pub") || name == "}" {
+        return None;
+    }
+    Some(StateVar {
+        name,
+        ty: ty.trim().to_string(),
+    })
+}
+
+/// Parse `name(ty a, ty b) ...` into the function/event name and its params.
+fn parse_signature(sig: &str) -> Option<(String, Vec<Param>)> {
+    let open = sig.find('(')?;
+    let close = sig[open..].find(')').map(|i| open + i)?;
+    let name = sig[..open].trim().to_string();
+    let args = &sig[open + 1..close];
+
+    let params = args
+        .split(',')
+        .filter_map(|arg| {
+            let arg = arg.trim();
+            if arg.is_empty() {
+                return None;
+            }
+            let mut words: Vec<&str> = arg.split_whitespace().collect();
+            // Drop Solidity data-location keywords (`memory`/`calldata`/`storage`).
+            words.retain(|w| !matches!(*w, "memory" | "calldata" | "storage" | "indexed"));
+            let param_name = words.pop()?.to_string();
+            let ty = words.join(" ");
+            Some(Param { name: param_name, ty })
+        })
+        .collect();
+
+    Some((name, params))
+}
+
+/// Render `(ty a, ty b)`-style Solidity parameters.
+fn render_solidity_params(params: &[Param]) -> String {
+    params
+        .iter()
+        .map(|p| format!("{} {}", p.ty, p.name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Render `(a: ty, b: ty)`-style Rust/ink! parameters.
+fn render_ink_params(params: &[Param]) -> String {
+    params
+        .iter()
+        .map(|p| format!("{}: {}", p.name, p.ty))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Render a function's captured body lines at `indent`, falling back to a
+/// TODO placeholder when nothing was captured (e.g. an interface stub).
+/// The lines are emitted as-is in the source dialect; `translate_expressions`
+/// rewrites the handful of known globals/macros over the whole output
+/// afterwards, so this only needs to worry about indentation.
+fn render_body(body: &[String], indent: &str) -> String {
+    if body.is_empty() {
+        return format!("{indent}// TODO: translate body\n");
+    }
+
+    body.iter()
+        .map(|line| format!("{indent}{line}\n"))
+        .collect()
+}
+
+fn extract_between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let start_idx = text.find(start)? + start.len();
+    let end_idx = text[start_idx..].find(end)? + start_idx;
+    Some(&text[start_idx..end_idx])
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in name.char_indices() {
+        if c.is_uppercase() {
+            if i != 0 {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
@@ -0,0 +1,53 @@
+//! honggfuzz target: feed arbitrary bytes to the analyzer and make sure it
+//! never panics and never reports a line number outside the source.
+//!
+//! Run with `cargo hfuzz run analyze_contract` from the `fuzz/` directory
+//! (see `fuzz/README.md`).
+
+use honggfuzz::fuzz;
+use mjolnir_analyzer::{Analyzer, analyze_contract, get_default_rules};
+
+fn main() {
+    loop {
+        fuzz!(|data: &[u8]| {
+            let Ok(code) = std::str::from_utf8(data) else {
+                return;
+            };
+
+            let max_line = code.lines().count() + 1;
+
+            // The convenience entry point, exercised through every default rule.
+            let results = analyze_contract(code);
+            for issue in &results.issues {
+                if let Some(line) = issue.line {
+                    assert!(
+                        (1..=max_line).contains(&line),
+                        "line {} out of bounds for {} line(s) of source",
+                        line,
+                        max_line
+                    );
+                }
+            }
+
+            // Every rule individually, so a single bad rule can't hide behind
+            // another rule that happens to stop the whole analyzer panicking.
+            for rule in get_default_rules() {
+                for issue in rule.analyze(code) {
+                    if let Some(line) = issue.line {
+                        assert!(
+                            (1..=max_line).contains(&line),
+                            "rule '{}' reported line {} out of bounds for {} line(s)",
+                            rule.id(),
+                            line,
+                            max_line
+                        );
+                    }
+                }
+            }
+
+            // Custom configs take a different code path (rule filtering,
+            // custom weights) and should be just as panic-free.
+            let _ = Analyzer::new().analyze(code);
+        });
+    }
+}